@@ -0,0 +1,88 @@
+//! Benchmarks of `SecurityIdentifier` construction, cloning and formatting.
+//!
+//! Run with and without the `inline` feature to compare the inline-first
+//! storage against the heap-only one:
+//!
+//! ```sh
+//! cargo bench --bench security_identifier
+//! cargo bench --bench security_identifier --features inline
+//! ```
+#![allow(missing_docs, reason = "Generated by criterion macros")]
+
+use core::hint::black_box;
+use criterion::{Criterion, criterion_group, criterion_main};
+use win_security_identifier::{SecurityIdentifier, SidIdentifierAuthority};
+
+const SHORT: [u32; 2] = [32, 544];
+const DOMAIN: [u32; 5] = [21, 1_004_336_348, 1_177_238_915, 682_003_330, 1001];
+const LONG: [u32; 15] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+fn bench_shape(c: &mut Criterion, name: &str, sub_authority: &[u32]) {
+    let Some(sid) =
+        SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, sub_authority)
+    else {
+        return;
+    };
+    let text = sid.to_string();
+    let bytes = sid.as_binary().to_vec();
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("try_new", |b| {
+        b.iter(|| {
+            SecurityIdentifier::try_new(
+                black_box(SidIdentifierAuthority::NT_AUTHORITY),
+                black_box(sub_authority),
+            )
+        });
+    });
+    group.bench_function("from_bytes", |b| {
+        b.iter(|| SecurityIdentifier::from_bytes(black_box(&bytes)));
+    });
+    group.bench_function("parse", |b| {
+        b.iter(|| black_box(text.as_str()).parse::<SecurityIdentifier>());
+    });
+    group.bench_function("clone", |b| b.iter(|| black_box(&sid).clone()));
+    group.finish();
+}
+
+fn bench_eq(c: &mut Criterion) {
+    let (Some(short), Some(long), Some(long_copy)) = (
+        SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, SHORT),
+        SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, LONG),
+        SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, LONG),
+    ) else {
+        return;
+    };
+
+    let mut group = c.benchmark_group("eq");
+    group.bench_function("same_len", |b| {
+        b.iter(|| black_box(&long) == black_box(&long_copy));
+    });
+    group.bench_function("different_len", |b| {
+        b.iter(|| black_box(&long) == black_box(&short));
+    });
+    group.finish();
+}
+
+fn bench_to_string(c: &mut Criterion) {
+    let Some(sid) = SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, DOMAIN)
+    else {
+        return;
+    };
+
+    let mut group = c.benchmark_group("to_string");
+    group.bench_function("display", |b| b.iter(|| black_box(&sid).to_string()));
+    group.bench_function("fast", |b| b.iter(|| black_box(&sid).to_string_fast()));
+    group.finish();
+}
+
+fn security_identifier(c: &mut Criterion) {
+    bench_shape(c, "short", &SHORT);
+    bench_shape(c, "domain", &DOMAIN);
+    bench_shape(c, "long", &LONG);
+    bench_eq(c);
+    bench_to_string(c);
+}
+
+criterion_group!(benches, security_identifier);
+criterion_main!(benches);
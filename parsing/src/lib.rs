@@ -10,6 +10,8 @@ const MIN_SUBAUTHORITY_COUNT_USIZE: usize = MIN_SUBAUTHORITY_COUNT as usize;
 pub const MAX_SUBAUTHORITY_COUNT: u8 = 15;
 const MAX_SUBAUTHORITY_COUNT_USIZE: usize = MAX_SUBAUTHORITY_COUNT as usize;
 
+/// Parsed parts of a SID string (`S-1-<authority>-<sub_authority>...`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SidComponents {
     /// The SID identifier authority value.
     pub identifier_authority: [u8; 6],
@@ -30,43 +32,54 @@ impl Display for InvalidSidFormat {
     }
 }
 
-impl FromStr for SidComponents {
-    type Err = InvalidSidFormat;
+impl SidComponents {
+    /// Parses a SID string, reporting which field made it invalid.
+    ///
+    /// Fields are the `-`-separated parts of the string: `0` is the `S` prefix,
+    /// `1` the revision, `2` the identifier authority and `3..` the
+    /// sub-authorities. A missing field is reported at the index where it was
+    /// expected.
+    ///
+    /// # Errors
+    /// Returns [`InvalidSidFormat`] together with the 0-based index of the
+    /// offending field.
     #[inline]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    pub fn parse_detailed(s: &str) -> Result<Self, (InvalidSidFormat, usize)> {
+        const SUB_AUTHORITY_START: usize = 3;
         let mut s_cmp = s.split('-');
         if !s_cmp
             .next()
             .is_some_and(|head| head.eq_ignore_ascii_case("s"))
         {
-            return Err(InvalidSidFormat);
+            return Err((InvalidSidFormat, 0));
         }
         let revision = s_cmp
             .next()
-            .ok_or(InvalidSidFormat)?
-            .parse::<u8>()
-            .map_err(|_| InvalidSidFormat)?;
+            .and_then(|s| s.parse::<u8>().ok())
+            .ok_or((InvalidSidFormat, 1))?;
 
         if revision != 1 {
-            return Err(InvalidSidFormat);
+            return Err((InvalidSidFormat, 1));
         }
 
         let identifier_authority = s_cmp
             .next()
-            .ok_or(InvalidSidFormat)
-            .and_then(|s| s.parse::<u64>().map_err(|_| InvalidSidFormat))
+            .and_then(|s| s.parse::<u64>().ok())
             .map(|value| {
-                let bytes = value.to_be_bytes();
-                #[expect(clippy::unwrap_used)]
-                bytes[2..].try_into().unwrap()
-            })?;
+                let [_, _, authority @ ..] = value.to_be_bytes();
+                authority
+            })
+            .ok_or((InvalidSidFormat, 2))?;
         let mut sub_authority = ArrayVec::<u32, MAX_SUBAUTHORITY_COUNT_USIZE>::new();
-        for item in s_cmp {
-            let item = item.parse::<u32>().map_err(|_| InvalidSidFormat)?;
-            sub_authority.try_push(item).map_err(|_| InvalidSidFormat)?;
+        for (index, item) in s_cmp.enumerate() {
+            let index = SUB_AUTHORITY_START + index;
+            let item = item.parse::<u32>().map_err(|_| (InvalidSidFormat, index))?;
+            sub_authority
+                .try_push(item)
+                .map_err(|_| (InvalidSidFormat, index))?;
         }
         if sub_authority.len() < MIN_SUBAUTHORITY_COUNT_USIZE {
-            return Err(InvalidSidFormat);
+            return Err((InvalidSidFormat, SUB_AUTHORITY_START));
         }
 
         Ok(Self {
@@ -75,3 +88,51 @@ impl FromStr for SidComponents {
         })
     }
 }
+
+impl FromStr for SidComponents {
+    type Err = InvalidSidFormat;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_detailed(s).map_err(|(err, _)| err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failing_index(s: &str) -> Option<usize> {
+        SidComponents::parse_detailed(s)
+            .err()
+            .map(|(_, index)| index)
+    }
+
+    #[test]
+    fn parse_detailed_reports_bad_sub_authority() {
+        assert_eq!(failing_index("S-1-5-21-42-x-7"), Some(5));
+        assert_eq!(failing_index("S-1-5-21-42-4294967296"), Some(5));
+    }
+
+    #[test]
+    fn parse_detailed_reports_header_fields() {
+        assert_eq!(failing_index("X-1-5-32"), Some(0));
+        assert_eq!(failing_index("S-2-5-32"), Some(1));
+        assert_eq!(failing_index("S-1"), Some(2));
+        assert_eq!(failing_index("S-1-five-32"), Some(2));
+        assert_eq!(failing_index("S-1-5"), Some(3));
+    }
+
+    #[test]
+    fn parse_detailed_reports_overflowing_count() {
+        assert_eq!(
+            failing_index("S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15-16"),
+            Some(18)
+        );
+    }
+
+    #[test]
+    fn parse_detailed_accepts_valid_sid() {
+        let components = SidComponents::parse_detailed("S-1-5-32-544").map_err(|(_, i)| i);
+        assert!(components.is_ok_and(|c| c.sub_authority.as_slice() == [32, 544]));
+    }
+}
@@ -0,0 +1,354 @@
+//! LRU cache of SID lookups.
+
+use crate::Sid;
+#[cfg(windows)]
+use crate::sid_lookup::{Error, RemoteSidResolver, SidLookup};
+use core::num::NonZeroUsize;
+use std::collections::HashMap;
+#[cfg(windows)]
+use std::ffi::OsStr;
+
+/// Source of the lookups cached by [`LruSidCache`].
+///
+/// On Windows it is implemented by [`LocalSidLookup`] and
+/// [`RemoteSidResolver`]; other implementations can stand in for the OS.
+pub trait SidLookupSource {
+    /// Successful lookup result, cloned out of the cache on hits.
+    type Output: Clone;
+    /// Error reported by a failed lookup.
+    type Error;
+
+    /// Resolves `sid`, with the same `Option<Result<..>>` shape as
+    /// [`Sid::lookup_local_sid`](crate::Sid::lookup_local_sid).
+    fn lookup(&mut self, sid: &Sid) -> Option<Result<Self::Output, Self::Error>>;
+}
+
+/// Lookups on the local machine, through [`Sid::lookup_local_sid`].
+#[cfg(windows)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LocalSidLookup;
+
+#[cfg(windows)]
+impl SidLookupSource for LocalSidLookup {
+    type Output = SidLookup;
+    type Error = Error;
+
+    #[inline]
+    fn lookup(&mut self, sid: &Sid) -> Option<Result<SidLookup, Error>> {
+        sid.lookup_local_sid()
+    }
+}
+
+#[cfg(windows)]
+impl SidLookupSource for RemoteSidResolver {
+    type Output = SidLookup;
+    type Error = Error;
+
+    #[inline]
+    fn lookup(&mut self, sid: &Sid) -> Option<Result<SidLookup, Error>> {
+        Self::lookup(self, sid)
+    }
+}
+
+/// Marks the end of the recency list.
+const NIL: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    key: Box<[u8]>,
+    value: T,
+    /// More recently used neighbour.
+    prev: usize,
+    /// Less recently used neighbour.
+    next: usize,
+}
+
+/// Least-recently-used cache in front of a [`SidLookupSource`].
+///
+/// Successful lookups are kept, keyed by the binary SID, until `capacity`
+/// distinct SIDs are cached; the least recently used one is then evicted.
+/// Failed lookups (`None` or `Some(Err(_))`) are not cached, so transient
+/// errors are retried on the next call.
+///
+/// # Examples
+/// ```no_run
+/// # #[cfg(windows)]
+/// # {
+/// use core::num::NonZeroUsize;
+/// use win_security_identifier::{well_known, LruSidCache};
+/// let mut cache = LruSidCache::local(NonZeroUsize::new(128).unwrap());
+/// let sid = well_known::LOCAL_SYSTEM.as_sid();
+/// let first = cache.lookup(sid).unwrap().unwrap();
+/// // Served from the cache, without calling the OS.
+/// let second = cache.lookup(sid).unwrap().unwrap();
+/// assert_eq!(first, second);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LruSidCache<S: SidLookupSource> {
+    source: S,
+    capacity: NonZeroUsize,
+    index: HashMap<Box<[u8]>, usize>,
+    entries: Vec<Entry<S::Output>>,
+    /// Most recently used entry.
+    head: usize,
+    /// Least recently used entry, evicted first.
+    tail: usize,
+}
+
+#[cfg(windows)]
+impl LruSidCache<LocalSidLookup> {
+    /// Creates a cache of local lookups ([`Sid::lookup_local_sid`]).
+    #[inline]
+    #[must_use]
+    pub fn local(capacity: NonZeroUsize) -> Self {
+        Self::new(LocalSidLookup, capacity)
+    }
+}
+
+#[cfg(windows)]
+impl LruSidCache<RemoteSidResolver> {
+    /// Creates a cache of lookups on `machine_name` ([`Sid::lookup_remote_sid`]).
+    ///
+    /// Returns `None` if the name contains an interior NUL.
+    #[inline]
+    #[must_use]
+    pub fn remote<M: AsRef<OsStr>>(machine_name: M, capacity: NonZeroUsize) -> Option<Self> {
+        RemoteSidResolver::new(machine_name).map(|resolver| Self::new(resolver, capacity))
+    }
+}
+
+impl<S: SidLookupSource> LruSidCache<S> {
+    /// Creates an empty cache holding at most `capacity` lookups of `source`.
+    #[inline]
+    #[must_use]
+    pub fn new(source: S, capacity: NonZeroUsize) -> Self {
+        Self {
+            source,
+            capacity,
+            index: HashMap::new(),
+            entries: Vec::new(),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+
+    /// Resolves `sid`, from the cache when possible.
+    ///
+    /// A hit marks `sid` as the most recently used entry. A miss calls the
+    /// source and caches a successful result, evicting the least recently used
+    /// entry if the cache is full.
+    #[inline]
+    pub fn lookup(&mut self, sid: &Sid) -> Option<Result<S::Output, S::Error>> {
+        if let Some(value) = self.get(sid) {
+            return Some(Ok(value.clone()));
+        }
+        let result = self.source.lookup(sid);
+        if let Some(Ok(value)) = &result {
+            self.insert(sid.as_binary(), value.clone());
+        }
+        result
+    }
+
+    /// Returns the cached result for `sid` without calling the source,
+    /// marking it as the most recently used entry.
+    #[inline]
+    pub fn get(&mut self, sid: &Sid) -> Option<&S::Output> {
+        let slot = *self.index.get(sid.as_binary())?;
+        self.move_to_front(slot);
+        self.entries.get(slot).map(|entry| &entry.value)
+    }
+
+    /// Returns `true` if a result for `sid` is cached.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, sid: &Sid) -> bool {
+        self.index.contains_key(sid.as_binary())
+    }
+
+    /// Returns the number of cached lookups.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of cached lookups.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    /// Removes every cached lookup.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.entries.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    /// Returns the lookup source.
+    #[inline]
+    #[must_use]
+    pub const fn source(&self) -> &S {
+        &self.source
+    }
+
+    fn insert(&mut self, key: &[u8], value: S::Output) {
+        let slot = if self.entries.len() < self.capacity.get() {
+            self.entries.push(Entry {
+                key: key.into(),
+                value,
+                prev: NIL,
+                next: NIL,
+            });
+            self.entries.len() - 1
+        } else {
+            // Reuse the slot of the least recently used entry.
+            let slot = self.tail;
+            self.unlink(slot);
+            let Some(entry) = self.entries.get_mut(slot) else {
+                return;
+            };
+            let evicted = core::mem::replace(&mut entry.key, key.into());
+            entry.value = value;
+            self.index.remove(&evicted);
+            slot
+        };
+        self.index.insert(key.into(), slot);
+        self.push_front(slot);
+    }
+
+    fn move_to_front(&mut self, slot: usize) {
+        if self.head != slot {
+            self.unlink(slot);
+            self.push_front(slot);
+        }
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let Some(&Entry { prev, next, .. }) = self.entries.get(slot) else {
+            return;
+        };
+        match self.entries.get_mut(prev) {
+            Some(entry) => entry.next = next,
+            None => self.head = next,
+        }
+        match self.entries.get_mut(next) {
+            Some(entry) => entry.prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        let old_head = self.head;
+        if let Some(entry) = self.entries.get_mut(slot) {
+            entry.prev = NIL;
+            entry.next = old_head;
+        }
+        match self.entries.get_mut(old_head) {
+            Some(entry) => entry.prev = slot,
+            None => self.tail = slot,
+        }
+        self.head = slot;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::{StackSid, well_known};
+
+    /// Resolves every SID to its string form and counts the calls.
+    #[derive(Debug, Default)]
+    struct CountingSource {
+        calls: usize,
+    }
+
+    impl SidLookupSource for CountingSource {
+        type Output = String;
+        type Error = ();
+
+        fn lookup(&mut self, sid: &Sid) -> Option<Result<String, ()>> {
+            self.calls += 1;
+            if sid.is_null() {
+                Some(Err(()))
+            } else {
+                Some(Ok(sid.to_string()))
+            }
+        }
+    }
+
+    fn cache(capacity: usize) -> LruSidCache<CountingSource> {
+        LruSidCache::new(
+            CountingSource::default(),
+            NonZeroUsize::new(capacity).unwrap(),
+        )
+    }
+
+    #[test]
+    fn second_lookup_hits_the_cache() {
+        let mut cache = cache(4);
+        let sid = well_known::LOCAL_SYSTEM.as_sid();
+        assert_eq!(cache.lookup(sid), Some(Ok("S-1-5-18".to_owned())));
+        assert_eq!(cache.lookup(sid), Some(Ok("S-1-5-18".to_owned())));
+        // An equal SID from another storage is the same key.
+        let copy = StackSid::from(sid);
+        assert_eq!(cache.lookup(copy.as_sid()), Some(Ok("S-1-5-18".to_owned())));
+        assert_eq!(cache.source().calls, 1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let mut cache = cache(4);
+        let sid = well_known::NULL.as_sid();
+        assert_eq!(cache.lookup(sid), Some(Err(())));
+        assert_eq!(cache.lookup(sid), Some(Err(())));
+        assert_eq!(cache.source().calls, 2);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = cache(2);
+        let system = well_known::LOCAL_SYSTEM.as_sid();
+        let users = well_known::BUILTIN_USERS.as_sid();
+        let admins = well_known::BUILTIN_ADMINISTRATORS.as_sid();
+        cache.lookup(system);
+        cache.lookup(users);
+        // Touch `system` so `users` becomes the least recently used.
+        assert_eq!(cache.get(system).unwrap(), "S-1-5-18");
+        cache.lookup(admins);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(system));
+        assert!(cache.contains(admins));
+        assert!(!cache.contains(users));
+        cache.lookup(users);
+        assert!(!cache.contains(system));
+        assert_eq!(cache.source().calls, 4);
+    }
+
+    #[test]
+    fn capacity_one_and_clear() {
+        let mut cache = cache(1);
+        cache.lookup(well_known::LOCAL_SYSTEM.as_sid());
+        cache.lookup(well_known::WORLD.as_sid());
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains(well_known::WORLD.as_sid()));
+        cache.clear();
+        assert!(cache.is_empty());
+        cache.lookup(well_known::WORLD.as_sid());
+        assert_eq!(cache.source().calls, 3);
+    }
+}
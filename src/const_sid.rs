@@ -234,6 +234,50 @@ where
         )]
         self.sub_authority[N - 1]
     }
+
+    /// Compares two SIDs in a `const` context, where the derived `PartialEq`
+    /// cannot be called.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, ConstSid, SidIdentifierAuthority};
+    /// const ADMINS: ConstSid<2> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [32, 544]);
+    /// const { assert!(ADMINS.const_eq(&well_known::BUILTIN_ADMINISTRATORS)) };
+    /// const { assert!(!ADMINS.const_eq(&well_known::BUILTIN_USERS)) };
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        if self.revision != other.revision || self.sub_authority_count != other.sub_authority_count
+        {
+            return false;
+        }
+        let mut authority = 0;
+        while authority < self.identifier_authority.value.len() {
+            #[expect(
+                clippy::indexing_slicing,
+                reason = "Both authorities are 6 bytes and the index is bounded by their length"
+            )]
+            if self.identifier_authority.value[authority]
+                != other.identifier_authority.value[authority]
+            {
+                return false;
+            }
+            authority += 1;
+        }
+        let mut index = 0;
+        while index < N {
+            #[expect(
+                clippy::indexing_slicing,
+                reason = "Both arrays have N elements and the index is below N"
+            )]
+            if self.sub_authority[index] != other.sub_authority[index] {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
 }
 
 impl<const N: usize> PartialEq<Sid> for ConstSid<N>
@@ -297,6 +341,26 @@ where
     }
 }
 
+impl<const N: usize> PartialEq<str> for ConstSid<N>
+where
+    [u32; N]: SidLenValid,
+{
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_sid() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ConstSid<N>
+where
+    [u32; N]: SidLenValid,
+{
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_sid() == *other
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<const N: usize> From<ConstSid<N>> for SecurityIdentifier
 where
@@ -350,7 +414,7 @@ where
 {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_sid())
+        Display::fmt(self.as_sid(), f)
     }
 }
 
@@ -374,6 +438,35 @@ mod test {
     use crate::well_known;
 
     use super::*;
+
+    #[test]
+    fn test_const_eq() {
+        const ADMINS: ConstSid<2> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [32, 544]);
+        const {
+            assert!(ADMINS.const_eq(&well_known::BUILTIN_ADMINISTRATORS));
+            assert!(!ADMINS.const_eq(&well_known::BUILTIN_USERS));
+            assert!(!well_known::WORLD.const_eq(&well_known::LOCAL));
+            assert!(well_known::EVERYONE.const_eq(&well_known::WORLD));
+        };
+        assert_eq!(
+            ADMINS.const_eq(&well_known::BUILTIN_POWER_USERS),
+            ADMINS == well_known::BUILTIN_POWER_USERS
+        );
+    }
+
+    #[test]
+    fn test_sid_to_const() {
+        let users = well_known::BUILTIN_USERS_SID;
+        let matching: Option<ConstSid<2>> = users.to_const();
+        assert_eq!(matching, Some(well_known::BUILTIN_USERS));
+        assert_eq!(users.to_const::<1>(), None);
+        assert_eq!(users.to_const::<3>(), None);
+        assert_eq!(
+            well_known::LOCAL_SYSTEM_SID.to_const(),
+            Some(well_known::LOCAL_SYSTEM)
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     pub fn test_hash() {
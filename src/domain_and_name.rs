@@ -0,0 +1,280 @@
+//! `no_std` parsing of `DOMAIN\Name` pairs with simple validation.
+//!
+//! - `DomainAndNameRef` borrows both parts from the parsed `&str`.
+//! - `Display` prints as `DOMAIN\Name`.
+//! - Optional validation is controlled by a lightweight `ParsePolicy`.
+//!
+//! The owned, `OsString`-based `DomainAndName` returned by Windows lookups
+//! builds on this module.
+
+use core::fmt::{self, Display};
+
+use thiserror::Error;
+
+/// Which component an error refers to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Component {
+    Domain,
+    Name,
+}
+
+impl Display for Component {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Domain => f.write_str("domain"),
+            Self::Name => f.write_str("name"),
+        }
+    }
+}
+
+/// Parsing/validation errors for `DOMAIN\Name`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DomainParsingError {
+    /// Input did not contain a `\` separator.
+    #[error("Missing '\\' separator")]
+    MissingSeparator,
+
+    /// Input contained more than one `\` separator.
+    #[error("Too many '\\' separators")]
+    TooManySeparators,
+
+    /// Left part is empty while policy forbids it.
+    #[error("Domain is empty")]
+    EmptyDomain,
+
+    /// Right part is empty while policy forbids it.
+    #[error("Name is empty")]
+    EmptyName,
+
+    /// A component exceeded the configured maximum length.
+    #[error("{which} too long: max={max}, actual={actual}")]
+    ComponentTooLong {
+        which: Component,
+        max: usize,
+        actual: usize,
+    },
+
+    /// A forbidden code unit/byte was found (e.g., `\` or NUL).
+    #[error("Forbidden code unit 0x{unit:02X} in {which} at index {index}")]
+    ForbiddenUnit {
+        which: Component,
+        unit: u32,
+        index: usize,
+    },
+}
+
+/// Simple, const-friendly validation policy.
+/// Validation itself happens at runtime to keep things straightforward.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParsePolicy {
+    /// Allow empty domain (e.g., `\User`)?
+    pub allow_empty_domain: bool,
+    /// Allow empty name (e.g., `DOMAIN\`)?
+    pub allow_empty_name: bool,
+    /// Optional max length for each component, counted in UTF-16 code units
+    /// (the unit used by Windows account names).
+    pub max_component_len: Option<usize>,
+    /// ASCII code points disallowed in components (default: backslash and NUL).
+    /// They are checked as UTF-16 code units equal to the `u8` value
+    /// (e.g. `b'\\' as u16`). NUL is always forbidden.
+    pub forbidden_ascii: &'static [u8],
+}
+
+impl ParsePolicy {
+    /// Sensible default for Windows-style `DOMAIN\Name`.
+    pub const DEFAULT: Self = Self {
+        allow_empty_domain: true,
+        allow_empty_name: false,
+        max_component_len: None,
+        forbidden_ascii: b"\\\0",
+    };
+
+    /// Const constructor for convenience.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        allow_empty_domain: bool,
+        allow_empty_name: bool,
+        max_component_len: Option<usize>,
+        forbidden_ascii: &'static [u8],
+    ) -> Self {
+        Self {
+            allow_empty_domain,
+            allow_empty_name,
+            max_component_len,
+            forbidden_ascii,
+        }
+    }
+
+    /// Validate a single component, given as UTF-16 code units, against this policy.
+    pub(crate) fn validate_units<I>(
+        &self,
+        which: Component,
+        units: I,
+    ) -> Result<(), DomainParsingError>
+    where
+        I: Iterator<Item = u16> + Clone,
+    {
+        if units.clone().next().is_none() {
+            return match which {
+                Component::Domain => {
+                    if self.allow_empty_domain {
+                        Ok(())
+                    } else {
+                        Err(DomainParsingError::EmptyDomain)
+                    }
+                }
+                Component::Name => {
+                    if self.allow_empty_name {
+                        Ok(())
+                    } else {
+                        Err(DomainParsingError::EmptyName)
+                    }
+                }
+            };
+        }
+
+        if let Some(max) = self.max_component_len {
+            let len = units.clone().count();
+            if len > max {
+                return Err(DomainParsingError::ComponentTooLong {
+                    which,
+                    max,
+                    actual: len,
+                });
+            }
+        }
+
+        for (index, unit) in units.enumerate() {
+            // NUL always forbidden (0), plus any ASCII units in policy
+            if unit == 0 || self.forbidden_ascii.iter().any(|&b| unit == u16::from(b)) {
+                return Err(DomainParsingError::ForbiddenUnit {
+                    which,
+                    unit: u32::from(unit),
+                    index,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ParsePolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Borrowed `DOMAIN\Name` pair, parsed without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DomainAndNameRef<'a> {
+    /// The domain part (before the `\`).
+    pub domain: &'a str,
+    /// The name part (after the `\`).
+    pub name: &'a str,
+}
+
+impl<'a> DomainAndNameRef<'a> {
+    /// Parse `"DOMAIN\Name"` with [`ParsePolicy::DEFAULT`].
+    ///
+    /// # Errors
+    /// See [`DomainParsingError`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::domain_and_name::DomainAndNameRef;
+    /// let parsed = DomainAndNameRef::parse(r"NT AUTHORITY\SYSTEM").unwrap();
+    /// assert_eq!(parsed.domain, "NT AUTHORITY");
+    /// assert_eq!(parsed.name, "SYSTEM");
+    /// ```
+    #[inline]
+    pub fn parse(s: &'a str) -> Result<Self, DomainParsingError> {
+        Self::parse_with_policy(&ParsePolicy::DEFAULT, s)
+    }
+
+    /// Parse `"DOMAIN\Name"` with a specific policy.
+    ///
+    /// # Errors
+    /// See [`DomainParsingError`] and [`ParsePolicy`].
+    #[inline]
+    pub fn parse_with_policy(policy: &ParsePolicy, s: &'a str) -> Result<Self, DomainParsingError> {
+        // Split into at most 3 parts to detect "too many separators"
+        let mut iter = s.splitn(3, '\\');
+        let domain = iter.next().ok_or(DomainParsingError::MissingSeparator)?;
+        let name = iter.next().ok_or(DomainParsingError::MissingSeparator)?;
+        if iter.next().is_some() {
+            return Err(DomainParsingError::TooManySeparators);
+        }
+        policy.validate_units(Component::Domain, domain.encode_utf16())?;
+        policy.validate_units(Component::Name, name.encode_utf16())?;
+        Ok(Self { domain, name })
+    }
+}
+
+impl Display for DomainAndNameRef<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\\{}", self.domain, self.name)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_borrows_parts() {
+        let parsed = DomainAndNameRef::parse(r"BUILTIN\Administrators").unwrap();
+        assert_eq!(
+            parsed,
+            DomainAndNameRef {
+                domain: "BUILTIN",
+                name: "Administrators"
+            }
+        );
+        assert_eq!(parsed.to_string(), r"BUILTIN\Administrators");
+    }
+
+    #[test]
+    fn separators_and_empty_parts() {
+        assert_eq!(
+            DomainAndNameRef::parse("NoSlash"),
+            Err(DomainParsingError::MissingSeparator)
+        );
+        assert_eq!(
+            DomainAndNameRef::parse(r"A\B\C"),
+            Err(DomainParsingError::TooManySeparators)
+        );
+        assert_eq!(
+            DomainAndNameRef::parse("DOMAIN\\"),
+            Err(DomainParsingError::EmptyName)
+        );
+        assert!(DomainAndNameRef::parse(r"\user").is_ok());
+    }
+
+    #[test]
+    fn length_is_counted_in_utf16_units() {
+        const P: ParsePolicy = ParsePolicy::new(true, false, Some(2), b"\\\0");
+        // One code point outside the BMP is two UTF-16 code units.
+        assert!(DomainAndNameRef::parse_with_policy(&P, "\u{1F600}\\ok").is_ok());
+        assert_eq!(
+            DomainAndNameRef::parse_with_policy(&P, "\u{1F600}a\\ok"),
+            Err(DomainParsingError::ComponentTooLong {
+                which: Component::Domain,
+                max: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            DomainAndNameRef::parse("ACME\\jo\0hn"),
+            Err(DomainParsingError::ForbiddenUnit {
+                which: Component::Name,
+                unit: 0,
+                index: 2
+            })
+        );
+    }
+}
@@ -1,4 +1,6 @@
 #[cfg(all(windows, feature = "std"))]
 mod windows;
+#[cfg(all(test, windows, feature = "std"))]
+pub use windows::token_restricted_sids;
 #[cfg(all(windows, feature = "std"))]
 pub use windows::{GetCurrentSid, TokenError};
@@ -1,13 +1,19 @@
+use crate::SecurityIdentifier;
 use crate::sid::Sid;
 mod token_error;
 use core::mem::MaybeUninit;
 use core::ptr;
-use std::os::windows::io::{AsRawHandle, FromRawHandle, OwnedHandle, RawHandle};
+use std::os::windows::io::{
+    AsHandle, AsRawHandle, BorrowedHandle, FromRawHandle, OwnedHandle, RawHandle,
+};
 pub use token_error::TokenError;
 use windows_sys::Win32::{
-    Foundation::GetLastError,
-    Security::{GetTokenInformation, TOKEN_QUERY, TOKEN_USER, TokenUser},
-    System::Threading::{GetCurrentProcess, OpenProcessToken},
+    Foundation::{ERROR_NO_TOKEN, GetLastError},
+    Security::{
+        GetTokenInformation, SID_AND_ATTRIBUTES, TOKEN_GROUPS, TOKEN_INFORMATION_CLASS,
+        TOKEN_QUERY, TOKEN_USER, TokenGroups, TokenRestrictedSids, TokenUser,
+    },
+    System::Threading::{GetCurrentProcess, GetCurrentThread, OpenProcessToken, OpenThreadToken},
 };
 pub trait GetCurrentSid: Sized
 where
@@ -33,81 +39,95 @@ where
         reason = "Too complex to inline"
     )]
     fn get_current_user_sid() -> Result<Self, TokenError> {
-        // --- Open the process token ------------------------------------------------
-        let mut raw_handle_mu: MaybeUninit<RawHandle> = MaybeUninit::uninit();
-
-        // SAFETY: GetCurrentProcess is side-effect free and can be called unconditionally.
-        let process_handle = unsafe { GetCurrentProcess() };
-        // SAFETY: FFI call; pointers are valid. We check the return value immediately.
-        let open_ok =
-            unsafe { OpenProcessToken(process_handle, TOKEN_QUERY, raw_handle_mu.as_mut_ptr()) };
-
-        if open_ok == 0 {
-            // SAFETY: GetLastError is side-effect free and can be called unconditionally.
-
-            use crate::TokenError;
-            // SAFETY: GetLastError can be called immediately after a failing FFI call.
-            let err = unsafe { GetLastError() };
-            return Err(TokenError::OpenTokenFailed(err));
-        }
-
-        // SAFETY: OpenProcessToken reported success; the handle is initialized.
-        let raw_handle: RawHandle = unsafe { raw_handle_mu.assume_init() };
-
-        // SAFETY: `raw_handle` is a valid owned handle obtained from the OS.
-        let token_handle: OwnedHandle = unsafe { OwnedHandle::from_raw_handle(raw_handle) };
-
-        // --- First GetTokenInformation to obtain required size ---------------------
-        let mut size: u32 = 0;
-        // SAFETY: Standard size-query pattern with null buffer and 0 length.
-        let first_ok = unsafe {
-            GetTokenInformation(
-                token_handle.as_raw_handle(),
-                TokenUser,
-                ptr::null_mut(),
-                0,
-                &raw mut size,
-            )
-        };
+        let token_handle = open_process_token()?;
+        token_user_sid(token_handle.as_handle())
+    }
 
-        if first_ok != 0 {
-            // Unexpected success: should fail to report size.
-
-            use crate::TokenError;
-            return Err(TokenError::GetTokenSizeFailed);
-        }
-
-        // --- Allocate buffer with reported size ------------------------------------
-        let mut buffer = vec![0u8; size as usize];
-
-        // SAFETY: Buffer pointer/length are consistent with allocation; size was set by the API.
-        let second_ok = unsafe {
-            GetTokenInformation(
-                token_handle.as_raw_handle(),
-                TokenUser,
-                buffer.as_mut_ptr().cast(),
-                size,
-                &raw mut size,
-            )
+    /// Retrieves the user's SID from the current thread token (Windows only).
+    ///
+    /// When the thread is impersonating a client, this is the client's SID.
+    /// When the thread has no token, the process token is used instead, so the
+    /// result is the same as [`GetCurrentSid::get_current_user_sid`].
+    ///
+    /// # Errors
+    /// Returns a `TokenError` when opening the token or querying it fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[cfg(windows)]
+    /// # {
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// use win_security_identifier::GetCurrentSid;
+    /// let sid = SecurityIdentifier::get_current_thread_user_sid().unwrap();
+    /// println!("{}", sid);
+    /// # }
+    /// ```
+    #[allow(
+        clippy::missing_inline_in_public_items,
+        reason = "Too complex to inline"
+    )]
+    fn get_current_thread_user_sid() -> Result<Self, TokenError> {
+        let token_handle = match open_thread_token()? {
+            Some(token_handle) => token_handle,
+            None => open_process_token()?,
         };
+        token_user_sid(token_handle.as_handle())
+    }
+
+    /// Retrieves the logon session SID (`S-1-5-5-X-Y`) from the process token (Windows only).
+    ///
+    /// This is the group flagged with `SE_GROUP_LOGON_ID` in the token groups.
+    ///
+    /// # Errors
+    /// Returns a `TokenError` when opening the token or querying it fails, or
+    /// [`TokenError::LogonSidNotFound`] when the token has no logon SID.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[cfg(windows)]
+    /// # {
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// use win_security_identifier::GetCurrentSid;
+    /// let sid = SecurityIdentifier::get_current_logon_sid().unwrap();
+    /// assert!(sid.is_logon_session());
+    /// # }
+    /// ```
+    #[allow(
+        clippy::missing_inline_in_public_items,
+        reason = "Too complex to inline"
+    )]
+    fn get_current_logon_sid() -> Result<Self, TokenError> {
+        let token_handle = open_process_token()?;
+        token_logon_sid(token_handle.as_handle())
+    }
 
-        if second_ok == 0 {
-            // SAFETY: GetLastError can be called immediately after a failing FFI call.
-            let err = unsafe { GetLastError() };
-            return Err(TokenError::GetTokenInfoFailed(err));
-        }
-        #[expect(
-            clippy::cast_ptr_alignment,
-            reason = "read_unaligned handles unaligned access"
-        )]
-        let token_user_ptr = buffer.as_ptr().cast::<TOKEN_USER>();
-        // SAFETY: TOKEN_USER is a plain data struct and can be read from a byte buffer.
-        let sid_ptr = unsafe { ptr::addr_of!((*token_user_ptr).User.Sid) };
-        // SAFETY: TOKEN_USER contains a PSID which is a pointer to a valid SID.
-        let raw_sid: *mut core::ffi::c_void = unsafe { ptr::read_unaligned(sid_ptr) };
-        // SAFETY: get the user Sid from the raw pointer structure.
-        let sid = unsafe { Sid::from_raw(raw_sid) };
-        Ok(sid.into())
+    /// Retrieves the restricting SIDs of the process token (Windows only).
+    ///
+    /// These are the SIDs listed in `TokenRestrictedSids`, used by the second
+    /// access check of restricted tokens. The list is empty when the process
+    /// token is not restricted.
+    ///
+    /// # Errors
+    /// Returns a `TokenError` when opening the token or querying it fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[cfg(windows)]
+    /// # {
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// use win_security_identifier::GetCurrentSid;
+    /// for sid in SecurityIdentifier::get_current_restricting_sids().unwrap() {
+    ///     println!("{}", sid);
+    /// }
+    /// # }
+    /// ```
+    #[allow(
+        clippy::missing_inline_in_public_items,
+        reason = "Too complex to inline"
+    )]
+    fn get_current_restricting_sids() -> Result<Vec<Self>, TokenError> {
+        let token_handle = open_process_token()?;
+        token_restricted_sids(token_handle.as_handle())
     }
 }
 
@@ -117,3 +137,214 @@ where
     for<'a> &'a Sid: Into<T>,
 {
 }
+
+impl SecurityIdentifier {
+    /// Reads the user's SID (`TokenUser`) from an arbitrary token (Windows only).
+    ///
+    /// Unlike [`GetCurrentSid`], the caller supplies the token, e.g. a
+    /// duplicated or impersonation token. It must be opened with `TOKEN_QUERY`
+    /// access.
+    ///
+    /// # Errors
+    /// Returns a `TokenError` when querying the token fails.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # #[cfg(windows)]
+    /// # {
+    /// use std::os::windows::io::{AsHandle, FromRawHandle, OwnedHandle};
+    /// use win_security_identifier::SecurityIdentifier;
+    /// use windows_sys::Win32::Security::TOKEN_QUERY;
+    /// use windows_sys::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+    ///
+    /// let mut raw_handle = std::ptr::null_mut();
+    /// // SAFETY: valid out pointer; the result is checked below.
+    /// let ok = unsafe { OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &raw mut raw_handle) };
+    /// assert_ne!(ok, 0, "OpenProcessToken failed");
+    /// // SAFETY: OpenProcessToken succeeded, so we own the returned handle.
+    /// let token = unsafe { OwnedHandle::from_raw_handle(raw_handle) };
+    /// let sid = SecurityIdentifier::from_token(token.as_handle()).unwrap();
+    /// println!("{}", sid);
+    /// # }
+    /// ```
+    #[allow(
+        clippy::missing_inline_in_public_items,
+        reason = "Too complex to inline"
+    )]
+    pub fn from_token(handle: BorrowedHandle<'_>) -> Result<Self, TokenError> {
+        token_user_sid(handle)
+    }
+}
+
+/// Opens the current process token for query.
+fn open_process_token() -> Result<OwnedHandle, TokenError> {
+    let mut raw_handle_mu: MaybeUninit<RawHandle> = MaybeUninit::uninit();
+
+    // SAFETY: GetCurrentProcess is side-effect free and can be called unconditionally.
+    let process_handle = unsafe { GetCurrentProcess() };
+    // SAFETY: FFI call; pointers are valid. We check the return value immediately.
+    let open_ok =
+        unsafe { OpenProcessToken(process_handle, TOKEN_QUERY, raw_handle_mu.as_mut_ptr()) };
+
+    if open_ok == 0 {
+        // SAFETY: GetLastError can be called immediately after a failing FFI call.
+        let err = unsafe { GetLastError() };
+        return Err(TokenError::OpenTokenFailed(err));
+    }
+
+    // SAFETY: OpenProcessToken reported success; the handle is initialized.
+    let raw_handle: RawHandle = unsafe { raw_handle_mu.assume_init() };
+
+    // SAFETY: `raw_handle` is a valid owned handle obtained from the OS.
+    Ok(unsafe { OwnedHandle::from_raw_handle(raw_handle) })
+}
+
+/// Opens the current thread token for query.
+///
+/// Returns `Ok(None)` when the thread is not impersonating (no thread token).
+fn open_thread_token() -> Result<Option<OwnedHandle>, TokenError> {
+    let mut raw_handle_mu: MaybeUninit<RawHandle> = MaybeUninit::uninit();
+
+    // SAFETY: GetCurrentThread is side-effect free and can be called unconditionally.
+    let thread_handle = unsafe { GetCurrentThread() };
+    // SAFETY: FFI call; pointers are valid. We check the return value immediately.
+    // `OpenAsSelf` is set so the access check uses the process security context,
+    // which allows reading the token even under a low impersonation level.
+    let open_ok =
+        unsafe { OpenThreadToken(thread_handle, TOKEN_QUERY, 1, raw_handle_mu.as_mut_ptr()) };
+
+    if open_ok == 0 {
+        // SAFETY: GetLastError can be called immediately after a failing FFI call.
+        let err = unsafe { GetLastError() };
+        return if err == ERROR_NO_TOKEN {
+            Ok(None)
+        } else {
+            Err(TokenError::OpenTokenFailed(err))
+        };
+    }
+
+    // SAFETY: OpenThreadToken reported success; the handle is initialized.
+    let raw_handle: RawHandle = unsafe { raw_handle_mu.assume_init() };
+
+    // SAFETY: `raw_handle` is a valid owned handle obtained from the OS.
+    Ok(Some(unsafe { OwnedHandle::from_raw_handle(raw_handle) }))
+}
+
+/// Queries `class` information of `token_handle` into a freshly allocated buffer.
+fn query_token_information(
+    token_handle: BorrowedHandle<'_>,
+    class: TOKEN_INFORMATION_CLASS,
+) -> Result<Vec<u8>, TokenError> {
+    // --- First GetTokenInformation to obtain required size ---------------------
+    let mut size: u32 = 0;
+    // SAFETY: Standard size-query pattern with null buffer and 0 length.
+    let first_ok = unsafe {
+        GetTokenInformation(
+            token_handle.as_raw_handle(),
+            class,
+            ptr::null_mut(),
+            0,
+            &raw mut size,
+        )
+    };
+
+    if first_ok != 0 {
+        // Unexpected success: should fail to report size.
+        return Err(TokenError::GetTokenSizeFailed);
+    }
+
+    // --- Allocate buffer with reported size ------------------------------------
+    let mut buffer = vec![0u8; size as usize];
+
+    // SAFETY: Buffer pointer/length are consistent with allocation; size was set by the API.
+    let second_ok = unsafe {
+        GetTokenInformation(
+            token_handle.as_raw_handle(),
+            class,
+            buffer.as_mut_ptr().cast(),
+            size,
+            &raw mut size,
+        )
+    };
+
+    if second_ok == 0 {
+        // SAFETY: GetLastError can be called immediately after a failing FFI call.
+        let err = unsafe { GetLastError() };
+        return Err(TokenError::GetTokenInfoFailed(err));
+    }
+    Ok(buffer)
+}
+
+/// Reads the `TokenUser` SID of `token_handle`.
+fn token_user_sid<T>(token_handle: BorrowedHandle<'_>) -> Result<T, TokenError>
+where
+    for<'a> &'a Sid: Into<T>,
+{
+    let buffer = query_token_information(token_handle, TokenUser)?;
+    #[expect(
+        clippy::cast_ptr_alignment,
+        reason = "read_unaligned handles unaligned access"
+    )]
+    let token_user_ptr = buffer.as_ptr().cast::<TOKEN_USER>();
+    // SAFETY: TOKEN_USER is a plain data struct and can be read from a byte buffer.
+    let sid_ptr = unsafe { ptr::addr_of!((*token_user_ptr).User.Sid) };
+    // SAFETY: TOKEN_USER contains a PSID which is a pointer to a valid SID.
+    let raw_sid: *mut core::ffi::c_void = unsafe { ptr::read_unaligned(sid_ptr) };
+    // SAFETY: get the user Sid from the raw pointer structure.
+    let sid = unsafe { Sid::from_raw(raw_sid) };
+    Ok(sid.into())
+}
+
+/// Iterates over the `SID_AND_ATTRIBUTES` entries of a `TOKEN_GROUPS` buffer.
+fn token_groups(buffer: &[u8]) -> impl Iterator<Item = SID_AND_ATTRIBUTES> + '_ {
+    #[expect(
+        clippy::cast_ptr_alignment,
+        reason = "read_unaligned handles unaligned access"
+    )]
+    let token_groups_ptr = buffer.as_ptr().cast::<TOKEN_GROUPS>();
+    // SAFETY: TOKEN_GROUPS is a plain data struct and can be read from a byte buffer.
+    let count_ptr = unsafe { ptr::addr_of!((*token_groups_ptr).GroupCount) };
+    // SAFETY: The buffer starts with the TOKEN_GROUPS header.
+    let group_count = unsafe { ptr::read_unaligned(count_ptr) };
+    // SAFETY: `Groups` is the trailing array of `GroupCount` entries.
+    let groups_ptr =
+        unsafe { ptr::addr_of!((*token_groups_ptr).Groups) }.cast::<SID_AND_ATTRIBUTES>();
+    (0..group_count as usize).map(move |index| {
+        // SAFETY: `index` is below `GroupCount`, so the entry lies inside the buffer.
+        let entry_ptr = unsafe { groups_ptr.add(index) };
+        // SAFETY: The entry is in bounds; read_unaligned handles alignment.
+        unsafe { ptr::read_unaligned(entry_ptr) }
+    })
+}
+
+/// Reads the logon session SID from the `TokenGroups` of `token_handle`.
+fn token_logon_sid<T>(token_handle: BorrowedHandle<'_>) -> Result<T, TokenError>
+where
+    for<'a> &'a Sid: Into<T>,
+{
+    /// `SE_GROUP_LOGON_ID` attribute (not exposed by the enabled `windows-sys` features).
+    const SE_GROUP_LOGON_ID: u32 = 0xC000_0000;
+
+    let buffer = query_token_information(token_handle, TokenGroups)?;
+    let group = token_groups(&buffer)
+        .find(|group| group.Attributes & SE_GROUP_LOGON_ID == SE_GROUP_LOGON_ID)
+        .ok_or(TokenError::LogonSidNotFound)?;
+    // SAFETY: The group PSID points to a valid SID inside `buffer`.
+    let sid = unsafe { Sid::from_raw(group.Sid) };
+    Ok(sid.into())
+}
+
+/// Reads the `TokenRestrictedSids` of `token_handle`.
+pub fn token_restricted_sids<T>(token_handle: BorrowedHandle<'_>) -> Result<Vec<T>, TokenError>
+where
+    for<'a> &'a Sid: Into<T>,
+{
+    let buffer = query_token_information(token_handle, TokenRestrictedSids)?;
+    Ok(token_groups(&buffer)
+        .map(|group| {
+            // SAFETY: The group PSID points to a valid SID inside `buffer`.
+            let sid = unsafe { Sid::from_raw(group.Sid) };
+            sid.into()
+        })
+        .collect())
+}
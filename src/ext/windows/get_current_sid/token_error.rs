@@ -5,7 +5,11 @@ use thiserror::Error;
 /// to report failures when working with the Windows security token API.
 ///
 /// Each variant corresponds to a specific failure point.
+///
+/// New token queries may add failure points, so this enum is `#[non_exhaustive]`;
+/// match it with a wildcard arm.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum TokenError {
     /// `OpenProcessToken` failed.
     ///
@@ -27,4 +31,8 @@ pub enum TokenError {
     /// Contains the Win32 error code returned by `GetLastError`.
     #[error("GetTokenInformation failed (error {0})")]
     GetTokenInfoFailed(u32),
+
+    /// The token groups contain no logon session SID (`SE_GROUP_LOGON_ID`).
+    #[error("No logon SID in the token groups")]
+    LogonSidNotFound,
 }
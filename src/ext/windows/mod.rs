@@ -1,2 +1,4 @@
 mod get_current_sid;
+#[cfg(test)]
+pub use get_current_sid::token_restricted_sids;
 pub use get_current_sid::{GetCurrentSid, TokenError};
@@ -0,0 +1,107 @@
+//! Deduplication pool for SIDs.
+
+use crate::Sid;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Pool handing out shared [`Arc<Sid>`] handles for identical SIDs.
+///
+/// Large ACLs or token dumps typically repeat the same handful of SIDs many
+/// times; interning them keeps a single allocation per distinct SID. Lookups
+/// rely on the `Hash`/`Eq` impls of [`Sid`].
+///
+/// # Examples
+/// ```rust
+/// # use std::sync::Arc;
+/// # use win_security_identifier::{well_known, SecurityIdentifier, SidInterner};
+/// let mut interner = SidInterner::new();
+/// let first = interner.intern(well_known::BUILTIN_USERS.as_sid());
+/// let owned = SecurityIdentifier::from(well_known::BUILTIN_USERS);
+/// let second = interner.intern(&owned);
+/// assert!(Arc::ptr_eq(&first, &second));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SidInterner {
+    sids: HashSet<Arc<Sid>>,
+}
+
+impl SidInterner {
+    /// Creates an empty pool.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared handle for `sid`, allocating it on first use.
+    #[inline]
+    pub fn intern(&mut self, sid: &Sid) -> Arc<Sid> {
+        if let Some(shared) = self.sids.get(sid) {
+            return Arc::clone(shared);
+        }
+        let shared = sid.to_arc();
+        self.sids.insert(Arc::clone(&shared));
+        shared
+    }
+
+    /// Returns the shared handle for `sid` if it was already interned.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, sid: &Sid) -> Option<Arc<Sid>> {
+        self.sids.get(sid).cloned()
+    }
+
+    /// Returns the number of distinct SIDs in the pool.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sids.len()
+    }
+
+    /// Returns `true` if no SID was interned yet.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sids.is_empty()
+    }
+
+    /// Drops the pool's handles to SIDs that are no longer used elsewhere.
+    #[inline]
+    pub fn shrink(&mut self) {
+        self.sids.retain(|sid| Arc::strong_count(sid) > 1);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::{SecurityIdentifier, well_known};
+
+    #[test]
+    fn identical_sids_share_arc() {
+        let mut interner = SidInterner::new();
+        assert!(interner.is_empty());
+        let parsed: SecurityIdentifier = "S-1-5-32-544".parse().unwrap();
+        let first = interner.intern(&parsed);
+        let second = interner.intern(well_known::BUILTIN_ADMINISTRATORS.as_sid());
+        let other = interner.intern(well_known::BUILTIN_USERS.as_sid());
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(*first, *parsed);
+        assert_eq!(interner.len(), 2);
+        assert!(Arc::ptr_eq(&interner.get(&parsed).unwrap(), &first));
+    }
+
+    #[test]
+    fn shrink_drops_unused() {
+        let mut interner = SidInterner::new();
+        let kept = interner.intern(well_known::LOCAL_SYSTEM.as_sid());
+        drop(interner.intern(well_known::WORLD.as_sid()));
+        interner.shrink();
+        assert_eq!(interner.len(), 1);
+        assert!(interner.get(&kept).is_some());
+        assert!(interner.get(well_known::WORLD.as_sid()).is_none());
+    }
+}
@@ -1,4 +1,7 @@
 pub trait SidLenValid {}
+
+/// Seals traits that must only be implemented by this crate's types.
+pub trait Sealed {}
 macro_rules! impl_valid {
     ($($n:literal),* $(,)?) => { $( impl SidLenValid for [u32; $n] {} )* };
 }
@@ -126,7 +126,7 @@ pub(crate) use security_identifier::test::arb_security_identifier;
 /// See also: [`Sid::identifier_authority`], [`ConstSid::identifier_authority`].
 pub use sid_identifier_authority::SidIdentifierAuthority;
 
-pub use sid::Sid;
+pub use sid::{AuthorityStyle, Sid};
 
 #[cfg(test)]
 #[allow(unused_imports)]
@@ -141,13 +141,32 @@ pub(crate) mod internal;
 /// See [`ConstSid`] for invariants and examples.
 pub use const_sid::ConstSid;
 
-pub use parsing::InvalidSidFormat;
+pub use parsing::{InvalidSidFormat, SidComponents};
 
 /// Internal utilities for validation and layout calculations.
 pub(crate) mod utils;
 
+#[cfg(feature = "cache")]
+mod cache;
+pub mod domain_and_name;
+#[cfg(feature = "intern")]
+mod intern;
+mod owned_sid;
+mod parse_error;
+pub mod rid;
+mod sddl;
 #[cfg(feature = "serde")]
 mod serde_impl;
 mod stack_sid;
 pub mod well_known;
-pub use stack_sid::StackSid;
+#[cfg(all(windows, feature = "cache"))]
+pub use cache::LocalSidLookup;
+#[cfg(feature = "cache")]
+pub use cache::{LruSidCache, SidLookupSource};
+#[cfg(feature = "intern")]
+pub use intern::SidInterner;
+pub use owned_sid::OwnedSid;
+pub use parse_error::ParseError;
+#[cfg(feature = "serde")]
+pub use sddl::SidSddl;
+pub use stack_sid::{StackSid, parse_sid_bytes};
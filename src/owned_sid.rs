@@ -0,0 +1,48 @@
+//! Trait abstracting over the owned SID types.
+
+#[cfg(feature = "alloc")]
+use crate::SecurityIdentifier;
+use crate::{Sid, StackSid, internal::Sealed};
+
+/// Owned SID type that can be built from and viewed as a [`Sid`].
+///
+/// Implemented by [`StackSid`] and `SecurityIdentifier`, so generic code can
+/// use a single bound instead of `AsRef<Sid>` plus `for<'a> &'a Sid: Into<T>`.
+/// This trait is sealed and cannot be implemented outside this crate.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::{well_known, OwnedSid, Sid, StackSid};
+/// fn copy_rid<T: OwnedSid>(sid: &Sid) -> u32 {
+///     let owned = T::from(sid);
+///     owned.as_ref().rid()
+/// }
+/// assert_eq!(copy_rid::<StackSid>(well_known::BUILTIN_USERS.as_sid()), 545);
+/// ```
+pub trait OwnedSid: AsRef<Sid> + for<'a> From<&'a Sid> + Sealed {}
+
+impl Sealed for StackSid {}
+impl OwnedSid for StackSid {}
+
+#[cfg(feature = "alloc")]
+impl Sealed for SecurityIdentifier {}
+#[cfg(feature = "alloc")]
+impl OwnedSid for SecurityIdentifier {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::well_known;
+
+    fn rid_of<T: OwnedSid>(sid: &Sid) -> u32 {
+        T::from(sid).as_ref().rid()
+    }
+
+    #[test]
+    fn generic_over_owned_sids() {
+        let admins = well_known::BUILTIN_ADMINISTRATORS;
+        assert_eq!(rid_of::<StackSid>(admins.as_sid()), 544);
+        #[cfg(feature = "alloc")]
+        assert_eq!(rid_of::<SecurityIdentifier>(admins.as_sid()), 544);
+    }
+}
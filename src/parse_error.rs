@@ -0,0 +1,35 @@
+//! Unified parsing error, available without `std`.
+
+use crate::InvalidSidFormat;
+use crate::domain_and_name::DomainParsingError;
+use thiserror::Error;
+
+/// Error returned when parsing structured SID data fails.
+///
+/// Both [`InvalidSidFormat`] and [`DomainParsingError`] convert into it, so
+/// code parsing SIDs and `DOMAIN\Name` pairs can use a single error type with `?`.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::{ParseError, StackSid};
+/// # use win_security_identifier::domain_and_name::DomainAndNameRef;
+/// fn parse(sid: &str, account: &str) -> Result<(StackSid, usize), ParseError> {
+///     let sid: StackSid = sid.parse()?;
+///     let account = DomainAndNameRef::parse(account)?;
+///     Ok((sid, account.name.len()))
+/// }
+/// assert!(parse("S-1-5-18", r"NT AUTHORITY\SYSTEM").is_ok());
+/// assert!(matches!(parse("S-1", r"NT AUTHORITY\SYSTEM"), Err(ParseError::Sid(_))));
+/// assert!(matches!(parse("S-1-5-18", "SYSTEM"), Err(ParseError::DomainAndName(_))));
+/// ```
+#[derive(Debug, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The SID itself is malformed.
+    #[error(transparent)]
+    Sid(#[from] InvalidSidFormat),
+
+    /// The `DOMAIN\Name` pair is malformed.
+    #[error(transparent)]
+    DomainAndName(#[from] DomainParsingError),
+}
@@ -0,0 +1,90 @@
+//! Well-known relative identifiers (RIDs).
+//!
+//! Source: <https://learn.microsoft.com/windows/win32/secauthz/well-known-sids>
+//!
+//! RID `0` is valid (e.g. `S-1-0-0`), so RIDs stay plain `u32`s; these
+//! constants only make builder calls such as
+//! [`SecurityIdentifier::with_rid_replaced`](crate::SecurityIdentifier::with_rid_replaced)
+//! easier to read.
+
+// ---- Domain and local accounts (S-1-5-21-<domain>-<rid>) ----
+
+/// Built-in administrator account.
+pub const ADMINISTRATOR: u32 = 500;
+/// Built-in guest account.
+pub const GUEST: u32 = 501;
+/// Kerberos Key Distribution Center service account.
+pub const KRBTGT: u32 = 502;
+/// Account managed by the system (`DefaultAccount`).
+pub const DEFAULT_ACCOUNT: u32 = 503;
+
+// ---- Domain groups (S-1-5-21-<domain>-<rid>) ----
+
+/// Domain Admins group.
+pub const DOMAIN_ADMINS: u32 = 512;
+/// Domain Users group.
+pub const DOMAIN_USERS: u32 = 513;
+/// Domain Guests group.
+pub const DOMAIN_GUESTS: u32 = 514;
+/// Domain Computers group.
+pub const DOMAIN_COMPUTERS: u32 = 515;
+/// Domain Controllers group.
+pub const DOMAIN_CONTROLLERS: u32 = 516;
+/// Cert Publishers group.
+pub const CERT_PUBLISHERS: u32 = 517;
+/// Schema Admins group (forest root domain only).
+pub const SCHEMA_ADMINS: u32 = 518;
+/// Enterprise Admins group (forest root domain only).
+pub const ENTERPRISE_ADMINS: u32 = 519;
+/// Group Policy Creator Owners group.
+pub const GROUP_POLICY_CREATOR_OWNERS: u32 = 520;
+/// Read-only Domain Controllers group.
+pub const READONLY_DOMAIN_CONTROLLERS: u32 = 521;
+/// Protected Users group.
+pub const PROTECTED_USERS: u32 = 525;
+
+// ---- BUILTIN domain (S-1-5-32-<rid>) ----
+
+/// The BUILTIN domain itself (`S-1-5-32`).
+pub const BUILTIN_DOMAIN: u32 = 32;
+/// BUILTIN\Administrators alias.
+pub const ALIAS_ADMINS: u32 = 544;
+/// BUILTIN\Users alias.
+pub const ALIAS_USERS: u32 = 545;
+/// BUILTIN\Guests alias.
+pub const ALIAS_GUESTS: u32 = 546;
+/// BUILTIN\Power Users alias.
+pub const ALIAS_POWER_USERS: u32 = 547;
+
+#[cfg(all(test, feature = "alloc"))]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::{SecurityIdentifier, well_known};
+
+    #[test]
+    fn derive_domain_accounts() {
+        let user: SecurityIdentifier = "S-1-5-21-1004336348-1177238915-682003330-1001"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            user.with_rid_replaced(ADMINISTRATOR),
+            "S-1-5-21-1004336348-1177238915-682003330-500"
+        );
+        assert_eq!(
+            user.with_rid_replaced(DOMAIN_ADMINS),
+            "S-1-5-21-1004336348-1177238915-682003330-512"
+        );
+    }
+
+    #[test]
+    fn builtin_aliases_match_well_known() {
+        assert_eq!(
+            well_known::BUILTIN_ADMINISTRATORS
+                .as_sid()
+                .get_sub_authorities(),
+            [BUILTIN_DOMAIN, ALIAS_ADMINS]
+        );
+        assert_eq!(well_known::BUILTIN_GUESTS.as_sid().rid(), ALIAS_GUESTS);
+    }
+}
@@ -0,0 +1,194 @@
+//! SDDL SID string aliases (e.g. `BA` for `S-1-5-32-544`).
+//!
+//! Source: <https://learn.microsoft.com/windows/win32/secauthz/sid-strings>
+//!
+//! Only the aliases that map to a fixed SID are listed; domain-relative ones
+//! (`DA`, `DU`, ...) depend on the machine or domain and are not supported.
+
+use crate::{Sid, SidIdentifierAuthority, StackSid};
+
+const WORLD: SidIdentifierAuthority = SidIdentifierAuthority::SECURITY_WORLD_AUTHORITY;
+const CREATOR: SidIdentifierAuthority = SidIdentifierAuthority::SECURITY_CREATOR_AUTHORITY;
+const NT: SidIdentifierAuthority = SidIdentifierAuthority::NT_AUTHORITY;
+const APP_PACKAGE: SidIdentifierAuthority = SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY;
+const MANDATORY_LABEL: SidIdentifierAuthority =
+    SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY;
+
+/// `(alias, identifier authority, sub-authorities)` of every supported SDDL alias.
+const SDDL_ALIASES: &[(&str, SidIdentifierAuthority, &[u32])] = &[
+    ("WD", WORLD, &[0]),
+    ("CO", CREATOR, &[0]),
+    ("CG", CREATOR, &[1]),
+    ("OW", CREATOR, &[4]),
+    ("NU", NT, &[2]),
+    ("IU", NT, &[4]),
+    ("SU", NT, &[6]),
+    ("AN", NT, &[7]),
+    ("ED", NT, &[9]),
+    ("PS", NT, &[10]),
+    ("AU", NT, &[11]),
+    ("RC", NT, &[12]),
+    ("SY", NT, &[18]),
+    ("LS", NT, &[19]),
+    ("NS", NT, &[20]),
+    ("WR", NT, &[33]),
+    ("BA", NT, &[32, 544]),
+    ("BU", NT, &[32, 545]),
+    ("BG", NT, &[32, 546]),
+    ("PU", NT, &[32, 547]),
+    ("AO", NT, &[32, 548]),
+    ("SO", NT, &[32, 549]),
+    ("PO", NT, &[32, 550]),
+    ("BO", NT, &[32, 551]),
+    ("RE", NT, &[32, 552]),
+    ("RU", NT, &[32, 554]),
+    ("RD", NT, &[32, 555]),
+    ("NO", NT, &[32, 556]),
+    ("MU", NT, &[32, 558]),
+    ("LU", NT, &[32, 559]),
+    ("IS", NT, &[32, 568]),
+    ("CY", NT, &[32, 569]),
+    ("ER", NT, &[32, 573]),
+    ("CD", NT, &[32, 574]),
+    ("RA", NT, &[32, 575]),
+    ("ES", NT, &[32, 576]),
+    ("MS", NT, &[32, 577]),
+    ("HA", NT, &[32, 578]),
+    ("AA", NT, &[32, 579]),
+    ("RM", NT, &[32, 580]),
+    ("UD", NT, &[84, 0, 0, 0, 0, 0]),
+    ("AC", APP_PACKAGE, &[2, 1]),
+    ("LW", MANDATORY_LABEL, &[4096]),
+    ("ME", MANDATORY_LABEL, &[8192]),
+    ("MP", MANDATORY_LABEL, &[8448]),
+    ("HI", MANDATORY_LABEL, &[12288]),
+    ("SI", MANDATORY_LABEL, &[16384]),
+];
+
+impl Sid {
+    /// Returns the two-letter SDDL alias of this SID (e.g. `"BA"`), if it has one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, StackSid};
+    /// assert_eq!(well_known::BUILTIN_ADMINISTRATORS.as_sid().sddl_alias(), Some("BA"));
+    /// let user: StackSid = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// assert_eq!(user.as_sid().sddl_alias(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sddl_alias(&self) -> Option<&'static str> {
+        SDDL_ALIASES
+            .iter()
+            .find(|(_, authority, sub_authority)| {
+                self.identifier_authority == *authority
+                    && self.get_sub_authorities() == *sub_authority
+            })
+            .map(|(alias, _, _)| *alias)
+    }
+
+    /// Returns the SDDL integrity level alias (e.g. `"HI"`) of a mandatory label SID (`S-1-16-*`).
+    ///
+    /// This is the value of the `ML` ACE trustee in an SDDL SACL, such as
+    /// `S:(ML;;NW;;;HI)`. Returns `None` for any other SID, including
+    /// integrity levels without an SDDL alias.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert_eq!(well_known::HIGH_INTEGRITY.as_sid().to_sddl_integrity(), Some("HI"));
+    /// assert_eq!(well_known::BUILTIN_ADMINISTRATORS.as_sid().to_sddl_integrity(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_sddl_integrity(&self) -> Option<&'static str> {
+        if self.identifier_authority == MANDATORY_LABEL {
+            self.sddl_alias()
+        } else {
+            None
+        }
+    }
+}
+
+impl StackSid {
+    /// Builds the SID designated by a two-letter SDDL alias (e.g. `"BA"`).
+    ///
+    /// The alias is matched case-insensitively. Returns `None` for unknown or
+    /// domain-relative aliases.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, StackSid};
+    /// assert_eq!(StackSid::from_sddl_alias("SY").unwrap(), well_known::LOCAL_SYSTEM);
+    /// assert!(StackSid::from_sddl_alias("XX").is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_sddl_alias(alias: &str) -> Option<Self> {
+        SDDL_ALIASES
+            .iter()
+            .find(|(name, _, _)| name.eq_ignore_ascii_case(alias))
+            .and_then(|(_, authority, sub_authority)| Self::try_new(*authority, sub_authority))
+    }
+}
+
+/// Serde wrapper writing SIDs as their SDDL alias in human-readable formats.
+///
+/// `SidSddl(&sid)` serializes to `"BA"` rather than `"S-1-5-32-544"` when the
+/// SID has an alias (see [`Sid::sddl_alias`]) and to the `S-1-...` form
+/// otherwise. Binary formats are unaffected. Deserializing into
+/// `SidSddl<StackSid>` or `SidSddl<SecurityIdentifier>` accepts both forms.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::{well_known, SidSddl};
+/// let json = serde_json::to_string(&SidSddl(well_known::BUILTIN_USERS.as_sid())).unwrap();
+/// assert_eq!(json, r#""BU""#);
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SidSddl<T>(pub T);
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::well_known;
+
+    #[test]
+    fn aliases_round_trip() {
+        for (alias, _, _) in SDDL_ALIASES {
+            let sid = StackSid::from_sddl_alias(alias).unwrap();
+            assert_eq!(sid.as_sid().sddl_alias(), Some(*alias));
+        }
+    }
+
+    #[test]
+    fn integrity_levels() {
+        for (level, alias) in [
+            (well_known::LOW_INTEGRITY, "LW"),
+            (well_known::MEDIUM_INTEGRITY, "ME"),
+            (well_known::MEDIUM_PLUS_INTEGRITY, "MP"),
+            (well_known::HIGH_INTEGRITY, "HI"),
+            (well_known::SYSTEM_INTEGRITY, "SI"),
+        ] {
+            assert_eq!(level.as_sid().to_sddl_integrity(), Some(alias));
+        }
+        let untrusted: StackSid = "S-1-16-0".parse().unwrap();
+        assert_eq!(untrusted.as_sid().to_sddl_integrity(), None);
+        assert_eq!(well_known::LOCAL_SYSTEM.as_sid().to_sddl_integrity(), None);
+        assert_eq!(well_known::WORLD.as_sid().to_sddl_integrity(), None);
+    }
+
+    #[test]
+    fn known_aliases() {
+        assert_eq!(well_known::WORLD.as_sid().sddl_alias(), Some("WD"));
+        assert_eq!(well_known::LOCAL_SYSTEM.as_sid().sddl_alias(), Some("SY"));
+        assert_eq!(
+            StackSid::from_sddl_alias("ba").unwrap(),
+            well_known::BUILTIN_ADMINISTRATORS
+        );
+        assert_eq!(well_known::NULL.as_sid().sddl_alias(), None);
+        assert!(StackSid::from_sddl_alias("DA").is_none());
+    }
+}
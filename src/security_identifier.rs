@@ -3,25 +3,41 @@ use crate::Sid;
 use crate::SidIdentifierAuthority;
 use crate::SidSizeInfo;
 use crate::StackSid;
+use crate::sid::MAX_SUBAUTHORITY_COUNT;
 use crate::utils;
 use crate::utils::sub_authority_size_guard;
 use crate::utils::validate_sid_bytes_unaligned;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use ::alloc::{borrow::ToOwned, boxed::Box};
+use ::alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use arrayvec::{ArrayString, ArrayVec};
+use cfg_if::cfg_if;
 use core::alloc::Layout;
 use core::fmt::{self, Debug, Display};
-use core::mem::offset_of;
+use core::hash::Hash;
 use core::ops::Deref;
+#[cfg(feature = "inline")]
+mod inline_storage;
 mod maybe_uninit;
 use core::borrow::{Borrow, BorrowMut};
 use core::ops::DerefMut;
-use core::ptr;
 use core::str::FromStr;
 use delegate::delegate;
+#[cfg(feature = "inline")]
+use inline_storage::{InlineSid, SidStorage};
 use maybe_uninit::MaybeUninitSecurityIdentifier;
 use parsing::SidComponents;
 #[cfg(feature = "std")]
 use std::borrow::ToOwned;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
 /// Owned, heap-allocated Windows **Security Identifier** (SID).
 ///
@@ -44,8 +60,22 @@ use std::borrow::ToOwned;
 ///     .expect("valid SID parts");
 /// println!("{}", sid); // e.g., "S-1-5-32-544"
 /// ```
+///
+/// # Inline storage
+/// With the `inline` feature, SIDs with at most
+/// [`INLINE_SUB_AUTHORITY_CAPACITY`](SecurityIdentifier::INLINE_SUB_AUTHORITY_CAPACITY)
+/// sub-authorities are stored inline and only larger ones spill to the heap.
+/// The `Deref<Target = Sid>` API is identical in both modes.
 pub struct SecurityIdentifier {
-    inner: Box<Sid>,
+    inner: Inner,
+}
+
+cfg_if! {
+    if #[cfg(feature = "inline")] {
+        type Inner = SidStorage;
+    } else {
+        type Inner = Box<Sid>;
+    }
 }
 
 impl Debug for SecurityIdentifier {
@@ -56,6 +86,10 @@ impl Debug for SecurityIdentifier {
 }
 
 impl SecurityIdentifier {
+    /// Maximum number of sub-authorities stored inline before spilling to the heap.
+    #[cfg(feature = "inline")]
+    pub const INLINE_SUB_AUTHORITY_CAPACITY: usize = inline_storage::INLINE_SUB_AUTHORITY_CAPACITY;
+
     /// Creates a new `SecurityIdentifier` from parts, validating input.
     ///
     /// Returns `None` if `sub_authority` length is out of bounds (not in 1..=15).
@@ -82,9 +116,110 @@ impl SecurityIdentifier {
         sub_authority: S,
     ) -> Option<Self> {
         let sub_authority = sub_authority.as_ref();
-        // SAFETY: sub_authority_count is correctly validated by guard.
-        sub_authority_size_guard(sub_authority.len())
-            .then_some(unsafe { Self::new_unchecked(identifier_authority, sub_authority) })
+        sub_authority_size_guard(sub_authority.len()).then(|| {
+            // SAFETY: sub_authority_count is correctly validated by guard.
+            unsafe { Self::new_unchecked(identifier_authority, sub_authority) }
+        })
+    }
+
+    /// Creates a new `SecurityIdentifier` from parts, like [`SecurityIdentifier::try_new`],
+    /// but reports invalid input as an error so it can be propagated with `?`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidSidFormat`] if `sub_authority` length is out of bounds (not in 1..=15).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{InvalidSidFormat, SecurityIdentifier, SidIdentifierAuthority};
+    /// let sid = SecurityIdentifier::try_new_checked(SidIdentifierAuthority::NT_AUTHORITY, [18])?;
+    /// assert_eq!(sid, "S-1-5-18");
+    /// assert!(SecurityIdentifier::try_new_checked(SidIdentifierAuthority::NT_AUTHORITY, []).is_err());
+    /// # Ok::<(), InvalidSidFormat>(())
+    /// ```
+    #[inline]
+    pub fn try_new_checked<I: Into<SidIdentifierAuthority>, S: AsRef<[u32]>>(
+        identifier_authority: I,
+        sub_authority: S,
+    ) -> Result<Self, InvalidSidFormat> {
+        Self::try_new(identifier_authority, sub_authority).ok_or(InvalidSidFormat)
+    }
+
+    /// Builds a `SecurityIdentifier` from already parsed [`SidComponents`].
+    ///
+    /// This lets a SID string be parsed once and turned into several SID types.
+    ///
+    /// # Errors
+    /// Returns [`InvalidSidFormat`] if the sub-authority list is empty
+    /// (`SidComponents` fields are public, so this is not guaranteed by parsing).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{SecurityIdentifier, SidComponents, SidIdentifierAuthority, StackSid};
+    /// let components: SidComponents = "S-1-5-32-544".parse().unwrap();
+    /// let owned = SecurityIdentifier::from_components(&components).unwrap();
+    /// let stack = StackSid::try_new(
+    ///     SidIdentifierAuthority::new(components.identifier_authority),
+    ///     &components.sub_authority,
+    /// ).unwrap();
+    /// assert_eq!(owned, stack);
+    /// ```
+    #[inline]
+    pub fn from_components(components: &SidComponents) -> Result<Self, InvalidSidFormat> {
+        Self::try_new_checked(
+            SidIdentifierAuthority::new(components.identifier_authority),
+            &components.sub_authority,
+        )
+    }
+
+    /// Unpacks a SID previously packed with [`Sid::try_to_u128`].
+    ///
+    /// Returns `None` when `value` does not follow the packing layout: revision
+    /// other than `1`, count other than `1` or `2`, or a non-zero second
+    /// sub-authority while the count is `1`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, SecurityIdentifier};
+    /// let packed = well_known::LOCAL_SYSTEM.as_sid().try_to_u128().unwrap();
+    /// let sid = SecurityIdentifier::try_from_u128(packed).unwrap();
+    /// assert_eq!(sid, well_known::LOCAL_SYSTEM);
+    /// assert!(SecurityIdentifier::try_from_u128(0).is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn try_from_u128(value: u128) -> Option<Self> {
+        let [
+            revision,
+            count,
+            a0,
+            a1,
+            a2,
+            a3,
+            a4,
+            a5,
+            s0,
+            s1,
+            s2,
+            s3,
+            f0,
+            f1,
+            f2,
+            f3,
+        ] = value.to_be_bytes();
+        if revision != Sid::REVISION {
+            return None;
+        }
+        let first = u32::from_be_bytes([f0, f1, f2, f3]);
+        let second = u32::from_be_bytes([s0, s1, s2, s3]);
+        let sub_authority: &[u32] = match count {
+            1 if second == 0 => &[first],
+            2 => &[first, second],
+            _ => return None,
+        };
+        Self::try_new(
+            SidIdentifierAuthority::new([a0, a1, a2, a3, a4, a5]),
+            sub_authority,
+        )
     }
 
     /// Creates a new `SecurityIdentifier` from parts **without validation**.
@@ -121,6 +256,12 @@ impl SecurityIdentifier {
         )]
         let sub_authority_count = sub_authority.len() as u8;
         let identifier_authority = identifier_authority.into();
+        #[cfg(feature = "inline")]
+        if let Some(inline) = InlineSid::try_new(identifier_authority, sub_authority) {
+            return Self {
+                inner: SidStorage::Inline(inline),
+            };
+        }
         // SAFETY: sub_authority_count is validated by guard.
         let size_info = unsafe { SidSizeInfo::from_count(sub_authority_count).unwrap_unchecked() };
         // Safety: The uninit SID will be correctly filled after.
@@ -168,11 +309,129 @@ impl SecurityIdentifier {
     /// ```
     #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidSidFormat> {
+        #[cfg(feature = "zerocopy")]
+        utils::validate_sid_bytes_zerocopy(bytes)?;
+        #[cfg(not(feature = "zerocopy"))]
         validate_sid_bytes_unaligned(bytes)?;
         // SAFETY: All check was done before
         Ok(unsafe { Self::from_bytes_unchecked(bytes) })
     }
 
+    /// Creates a `SecurityIdentifier` from a binary SID, inferring the
+    /// sub-authority count from the length of `bytes`.
+    ///
+    /// This is a **repair path** for blobs whose declared `sub_authority_count`
+    /// does not match their length (e.g. produced by buggy serializers): the
+    /// header is rewritten with the count implied by `bytes.len()` instead of
+    /// being trusted. Prefer [`from_bytes`](Self::from_bytes), which rejects
+    /// such inconsistent input.
+    ///
+    /// # Errors
+    /// - [`InvalidSidFormat`] if the length is not `8 + 4 * n` bytes with
+    ///   `n` in `1..=15`, or the revision is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// // Declares 5 sub-authorities but only carries 2.
+    /// let bytes = [1, 5, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 32, 2, 0, 0];
+    /// assert!(SecurityIdentifier::from_bytes(&bytes).is_err());
+    /// let sid = SecurityIdentifier::from_bytes_inferring_count(&bytes).unwrap();
+    /// assert_eq!(sid, "S-1-5-32-544");
+    /// ```
+    #[inline]
+    pub fn from_bytes_inferring_count(bytes: &[u8]) -> Result<Self, InvalidSidFormat> {
+        const MAX_SIZE: usize = SidSizeInfo::MAX.get_layout().size();
+        let size_info = SidSizeInfo::from_full_size(bytes.len()).ok_or(InvalidSidFormat)?;
+        let mut buffer = [0u8; MAX_SIZE];
+        let fixed = buffer.get_mut(..bytes.len()).ok_or(InvalidSidFormat)?;
+        fixed.copy_from_slice(bytes);
+        let (header, _) = fixed.split_first_chunk_mut::<2>().ok_or(InvalidSidFormat)?;
+        header[1] = size_info.get_sub_authority_count();
+        Self::from_bytes(fixed)
+    }
+
+    /// Creates a `SecurityIdentifier` from a binary SID whose sub-authorities
+    /// are big-endian (network order).
+    ///
+    /// Unlike [`from_bytes`](Self::from_bytes), which expects the Windows native
+    /// layout with little-endian sub-authorities, each sub-authority `u32` is
+    /// byte-swapped here. The revision, count and identifier authority (already
+    /// big-endian in both layouts) are read unchanged. See [`Sid::to_bytes_be`]
+    /// for the reverse conversion.
+    ///
+    /// # Errors
+    /// - [`InvalidSidFormat`] If the byte slice is not a valid SID format.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// let bytes: [u8; 16] = [
+    ///     1, 2, 0, 0, 0, 0, 0, 5, // Header, NT AUTHORITY
+    ///     0, 0, 0, 32,            // SubAuthority[0] = 32
+    ///     0, 0, 2, 32,            // SubAuthority[1] = 544 (0x220 big endian)
+    /// ];
+    /// let sid = SecurityIdentifier::from_bytes_be(&bytes).unwrap();
+    /// assert_eq!(sid, "S-1-5-32-544");
+    /// ```
+    #[inline]
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<Self, InvalidSidFormat> {
+        const MAX_SIZE: usize = SidSizeInfo::MAX.get_layout().size();
+        validate_sid_bytes_unaligned(bytes)?;
+        let mut buffer = [0u8; MAX_SIZE];
+        let native = buffer.get_mut(..bytes.len()).ok_or(InvalidSidFormat)?;
+        native.copy_from_slice(bytes);
+        swap_sub_authority_bytes(native);
+        // SAFETY: Validated above; swapping the sub-authority bytes keeps the length and header.
+        Ok(unsafe { Self::from_bytes_unchecked(native) })
+    }
+
+    /// Parses a UTF-16 SID string such as `S-1-5-32-544`.
+    ///
+    /// A single trailing NUL terminator is ignored, so a buffer filled by a
+    /// Windows API can be passed as is. The string is decoded into a stack
+    /// buffer, so no allocation happens besides the resulting SID.
+    ///
+    /// # Errors
+    /// - [`InvalidSidFormat`] if `units` is not valid UTF-16 or not a valid SID string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// let wide: Vec<u16> = "S-1-5-32-544".encode_utf16().collect();
+    /// let sid = SecurityIdentifier::from_wide_str(&wide).unwrap();
+    /// assert_eq!(sid, "S-1-5-32-544");
+    /// ```
+    #[inline]
+    pub fn from_wide_str(units: &[u16]) -> Result<Self, InvalidSidFormat> {
+        let units = units.strip_suffix(&[0]).unwrap_or(units);
+        let mut text = ArrayString::<256>::new();
+        for c in char::decode_utf16(units.iter().copied()) {
+            text.try_push(c.map_err(|_| InvalidSidFormat)?)
+                .map_err(|_| InvalidSidFormat)?;
+        }
+        text.parse()
+    }
+
+    /// Parses a SID string held in an [`OsStr`](std::ffi::OsStr), e.g. from an `OsString`.
+    ///
+    /// # Errors
+    /// - [`InvalidSidFormat`] if `s` is not valid UTF-8 or not a valid SID string.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::ffi::OsString;
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// let text = OsString::from("S-1-5-32-544");
+    /// let sid = SecurityIdentifier::from_os_str(&text).unwrap();
+    /// assert_eq!(sid, "S-1-5-32-544");
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_os_str(s: &std::ffi::OsStr) -> Result<Self, InvalidSidFormat> {
+        s.to_str().ok_or(InvalidSidFormat)?.parse()
+    }
+
     /// Builds a `SecurityIdentifier` from raw bytes without validation.
     ///
     /// # Safety
@@ -181,26 +440,14 @@ impl SecurityIdentifier {
     /// layout. Passing invalid bytes results in undefined behavior.
     #[inline]
     unsafe fn from_bytes_unchecked(bytes: &[u8]) -> Self {
-        // SAFETY: All safety criteron are described in the doc
-        let size_info = unsafe {
-            #[expect(
-                clippy::indexing_slicing,
-                reason = "It's the unchecked version safety is precised in the doc."
-            )]
-            SidSizeInfo::from_count(bytes[offset_of!(Sid, sub_authority_count)]).unwrap_unchecked()
-        };
-        // Safety: The uninit SID is properly initialized by copying from `self` after.
-        let mut uninit = MaybeUninitSecurityIdentifier::alloc(&size_info);
-        // Safety: We copy all the bytes from a valid SID of the same size.
-        unsafe {
-            ptr::copy_nonoverlapping(
-                bytes.as_ptr(),
-                uninit.as_mut_ptr().cast::<u8>(),
-                size_info.get_layout().size(),
-            );
+        #[cfg(feature = "inline")]
+        if let Some(inline) = InlineSid::try_from_bytes(bytes) {
+            return Self {
+                inner: SidStorage::Inline(inline),
+            };
         }
-        // Safety: all is written so we can init.
-        unsafe { uninit.assume_init() }
+        // SAFETY: Same precondition as this method.
+        Self::from(unsafe { MaybeUninitSecurityIdentifier::boxed_from_bytes(bytes) })
     }
 
     /// Returns a reference to this `SecurityIdentifier` as a dynamically-sized [`Sid`].
@@ -221,7 +468,13 @@ impl SecurityIdentifier {
     #[inline]
     #[must_use]
     pub fn as_sid(&self) -> &Sid {
-        self.inner.as_ref()
+        let sid: &Sid = &self.inner;
+        debug_assert_eq!(
+            sid.sub_authority.len(),
+            sid.sub_authority_count as usize,
+            "sub_authority_count is out of sync with the backing allocation"
+        );
+        sid
     }
 
     /// Returns a mut reference to this `SecurityIdentifier` as a dynamically-sized [`Sid`].
@@ -253,7 +506,122 @@ impl SecurityIdentifier {
     /// ```
     #[inline]
     pub fn as_sid_mut(&mut self) -> &mut Sid {
-        self.inner.as_mut()
+        let sid: &mut Sid = &mut self.inner;
+        debug_assert_eq!(
+            sid.sub_authority.len(),
+            sid.sub_authority_count as usize,
+            "sub_authority_count is out of sync with the backing allocation"
+        );
+        sid
+    }
+
+    /// Appends `rid` as a new last sub-authority, in place.
+    ///
+    /// The SID is reallocated with the larger layout and the existing
+    /// sub-authorities are copied over.
+    ///
+    /// # Errors
+    /// - [`InvalidSidFormat`] if the SID already has the maximum of 15
+    ///   sub-authorities; `self` is left unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// let mut sid: SecurityIdentifier = "S-1-5-32".parse().unwrap();
+    /// sid.push_sub_authority(544).unwrap();
+    /// assert_eq!(sid, "S-1-5-32-544");
+    /// ```
+    #[inline]
+    pub fn push_sub_authority(&mut self, rid: u32) -> Result<(), InvalidSidFormat> {
+        let mut sub_authority = ArrayVec::<u32, { MAX_SUBAUTHORITY_COUNT as usize }>::new();
+        sub_authority
+            .try_extend_from_slice(self.get_sub_authorities())
+            .map_err(|_| InvalidSidFormat)?;
+        sub_authority.try_push(rid).map_err(|_| InvalidSidFormat)?;
+        // SAFETY: `sub_authority` holds at least one and at most `MAX_SUBAUTHORITY_COUNT` items.
+        unsafe { self.reallocate_with(&sub_authority) };
+        Ok(())
+    }
+
+    /// Removes and returns the last sub-authority (the RID), in place.
+    ///
+    /// The SID is reallocated with the smaller layout. Returns `None`, leaving
+    /// `self` unchanged, when only one sub-authority remains since a SID needs
+    /// at least one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// let mut sid: SecurityIdentifier = "S-1-5-32-544".parse().unwrap();
+    /// assert_eq!(sid.pop_sub_authority(), Some(544));
+    /// assert_eq!(sid, "S-1-5-32");
+    /// assert_eq!(sid.pop_sub_authority(), None);
+    /// ```
+    #[inline]
+    pub fn pop_sub_authority(&mut self) -> Option<u32> {
+        let (&rid, rest) = self
+            .get_sub_authorities()
+            .split_last()
+            .filter(|(_, rest)| !rest.is_empty())?;
+        let mut sub_authority = ArrayVec::<u32, { MAX_SUBAUTHORITY_COUNT as usize }>::new();
+        sub_authority.try_extend_from_slice(rest).ok()?;
+        // SAFETY: `sub_authority` holds at least one and fewer than `MAX_SUBAUTHORITY_COUNT` items.
+        unsafe { self.reallocate_with(&sub_authority) };
+        Some(rid)
+    }
+
+    /// Replaces this SID with one holding `sub_authority`, keeping the identifier authority.
+    ///
+    /// The storage is reallocated for the new sub-authority count.
+    ///
+    /// # Safety
+    /// `sub_authority` must hold between 1 and `MAX_SUBAUTHORITY_COUNT` items.
+    unsafe fn reallocate_with(&mut self, sub_authority: &[u32]) {
+        // SAFETY: Same precondition as this method.
+        *self = unsafe { Self::new_unchecked(self.identifier_authority, sub_authority) };
+    }
+
+    /// Returns a copy of this SID with its last sub-authority (the RID) replaced by `rid`.
+    ///
+    /// The sub-authority count is kept, which makes this handy to derive a sibling
+    /// account in the same domain.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{rid, SecurityIdentifier};
+    /// let admin: SecurityIdentifier = "S-1-5-21-1-2-3-500".parse().unwrap();
+    /// let guest = admin.with_rid_replaced(rid::GUEST);
+    /// assert_eq!(guest, "S-1-5-21-1-2-3-501");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_rid_replaced(&self, rid: u32) -> Self {
+        let mut sid = self.clone();
+        if let Some(last) = sid.sub_authorities_mut().last_mut() {
+            *last = rid;
+        }
+        sid
+    }
+
+    /// Returns a copy of this SID with `delta` added to its RID, or `None` if the
+    /// new RID does not fit in a `u32`.
+    ///
+    /// Like [`with_rid_replaced`](Self::with_rid_replaced), the sub-authority count is kept.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SecurityIdentifier;
+    /// let admin: SecurityIdentifier = "S-1-5-21-1-2-3-500".parse().unwrap();
+    /// assert_eq!(admin.with_rid_offset(12).unwrap(), "S-1-5-21-1-2-3-512");
+    /// assert_eq!(admin.with_rid_offset(-501), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_rid_offset(&self, delta: i64) -> Option<Self> {
+        let rid = i64::from(self.rid()).checked_add(delta)?;
+        u32::try_from(rid)
+            .ok()
+            .map(|rid| self.with_rid_replaced(rid))
     }
 }
 
@@ -266,6 +634,15 @@ impl TryFrom<&[u8]> for SecurityIdentifier {
     }
 }
 
+impl TryFrom<&str> for SecurityIdentifier {
+    type Error = InvalidSidFormat;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl<'a> From<&'a Sid> for SecurityIdentifier {
     #[inline]
     fn from(value: &'a Sid) -> Self {
@@ -293,95 +670,385 @@ impl FromStr for SecurityIdentifier {
     }
 }
 
-impl ToOwned for Sid {
-    type Owned = super::SecurityIdentifier;
+#[cfg(feature = "std")]
+impl SecurityIdentifier {
+    /// Reads one binary SID from `reader`, consuming exactly its length.
+    ///
+    /// The 2-byte header is read first to learn the sub-authority count, then
+    /// the identifier authority and exactly `count` sub-authorities, so SIDs
+    /// embedded in larger binary structures can be read in place.
+    ///
+    /// # Errors
+    /// - [`io::ErrorKind::InvalidData`] if the SID is malformed.
+    /// - Any error returned by `reader` (including
+    ///   [`io::ErrorKind::UnexpectedEof`] when it ends before the SID does).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, SecurityIdentifier};
+    /// use std::io::Cursor;
+    /// let mut bytes = well_known::BUILTIN_USERS.as_sid().as_binary().to_vec();
+    /// bytes.extend_from_slice(&[0xAA, 0xBB]);
+    /// let mut cursor = Cursor::new(bytes);
+    /// let sid = SecurityIdentifier::read_from(&mut cursor).unwrap();
+    /// assert_eq!(sid, well_known::BUILTIN_USERS);
+    /// assert_eq!(cursor.position(), 16);
+    /// ```
     #[inline]
-    fn to_owned(&self) -> Self::Owned {
-        self.into()
+    pub fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        const MAX_SIZE: usize = SidSizeInfo::MAX.get_layout().size();
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, InvalidSidFormat);
+        let mut header = [0u8; 2];
+        reader.read_exact(&mut header)?;
+        let [_, count] = header;
+        let size = SidSizeInfo::from_count(count)
+            .ok_or_else(invalid)?
+            .get_layout()
+            .size();
+        let mut buffer = [0u8; MAX_SIZE];
+        let bytes = buffer.get_mut(..size).ok_or_else(invalid)?;
+        let (bytes_header, rest) = bytes.split_first_chunk_mut::<2>().ok_or_else(invalid)?;
+        *bytes_header = header;
+        reader.read_exact(rest)?;
+        Self::from_bytes(bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
     }
-}
 
-impl Borrow<Sid> for SecurityIdentifier {
+    /// Reads `n` consecutive binary SIDs from `reader` with [`read_from`](Self::read_from).
+    ///
+    /// Reading stops at the first error; the SIDs read so far are dropped and
+    /// `reader` is left after the failing one. `n` may come from untrusted
+    /// input: the result is not preallocated for `n` SIDs up front.
+    ///
+    /// # Errors
+    /// Same as [`read_from`](Self::read_from).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, SecurityIdentifier};
+    /// use std::io::Cursor;
+    /// let mut bytes = well_known::BUILTIN_USERS.as_sid().as_binary().to_vec();
+    /// bytes.extend_from_slice(well_known::LOCAL_SYSTEM.as_sid().as_binary());
+    /// let sids = SecurityIdentifier::read_many(&mut Cursor::new(bytes), 2).unwrap();
+    /// assert_eq!(sids.len(), 2);
+    /// assert_eq!(sids[1], well_known::LOCAL_SYSTEM);
+    /// ```
     #[inline]
-    fn borrow(&self) -> &Sid {
-        self.as_sid()
+    pub fn read_many<R: Read>(reader: &mut R, n: usize) -> io::Result<Vec<Self>> {
+        (0..n).map(|_| Self::read_from(reader)).collect()
     }
 }
 
-impl BorrowMut<Sid> for SecurityIdentifier {
-    #[inline]
-    fn borrow_mut(&mut self) -> &mut Sid {
-        self.as_sid_mut()
+/// Reverses the byte order of every sub-authority of a binary SID in place.
+fn swap_sub_authority_bytes(bytes: &mut [u8]) {
+    if let Some((_, sub_authorities)) = bytes.split_first_chunk_mut::<8>() {
+        for sub_authority in sub_authorities.chunks_exact_mut(4) {
+            sub_authority.reverse();
+        }
     }
 }
 
-impl Deref for SecurityIdentifier {
-    type Target = Sid;
+impl Sid {
+    /// Allocates a `Box<Sid>` holding a copy of this SID.
+    ///
+    /// Useful when a bare `Box<Sid>` is wanted (e.g. as a map key) without the
+    /// [`SecurityIdentifier`] wrapper.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, SecurityIdentifier, Sid};
+    /// let boxed: Box<Sid> = well_known::BUILTIN_USERS.as_sid().to_boxed();
+    /// assert_eq!(boxed.to_string(), "S-1-5-32-545");
+    /// assert_eq!(SecurityIdentifier::from(boxed), well_known::BUILTIN_USERS);
+    /// ```
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+    #[must_use]
+    pub fn to_boxed(&self) -> Box<Self> {
+        // Safety: `self` is a valid SID so its binary representation is valid.
+        unsafe { MaybeUninitSecurityIdentifier::boxed_from_bytes(self.as_binary()) }
     }
-}
 
-impl DerefMut for SecurityIdentifier {
-    delegate!(
-        to self.inner {
-            #[inline]
-            fn deref_mut(&mut self) -> &mut Sid;
-        }
-    );
-}
-
-impl AsRef<Sid> for SecurityIdentifier {
-    delegate! {
-        to self.inner {
-            #[inline]
-            fn as_ref(&self) -> &Sid;
-        }
+    /// Allocates an `Arc<Sid>` holding a copy of this SID.
+    ///
+    /// The SID is written straight into the `Arc` allocation, without going
+    /// through an intermediate `Box<Sid>`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use win_security_identifier::{well_known, Sid};
+    /// let shared: Arc<Sid> = well_known::BUILTIN_USERS.as_sid().to_arc();
+    /// assert_eq!(*shared, *well_known::BUILTIN_USERS.as_sid());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_arc(&self) -> Arc<Self> {
+        // Safety: `self` is a valid SID so its binary representation is valid.
+        unsafe { MaybeUninitSecurityIdentifier::arc_from_bytes(self.as_binary()) }
     }
-}
 
-impl AsMut<Sid> for SecurityIdentifier {
-    delegate! {
-        to self.inner {
-            #[inline]
-            fn as_mut(&mut self) -> &mut Sid;
-        }
+    /// Returns the name of the `HKEY_USERS` subkey holding this account's hive.
+    ///
+    /// The registry uses the canonical `S-1-...` string, as produced by
+    /// `Display`. The per-user classes hive is loaded under a separate
+    /// `<SID>_Classes` key; see [`registry_classes_subkey`](Self::registry_classes_subkey).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::StackSid;
+    /// let user: StackSid = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// assert_eq!(user.as_sid().registry_subkey(), "S-1-5-21-1-2-3-1001");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn registry_subkey(&self) -> String {
+        self.to_string_fast()
     }
-}
 
-impl Clone for SecurityIdentifier {
+    /// Returns the name of the `HKEY_USERS` subkey holding this account's
+    /// classes hive (`<SID>_Classes`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::StackSid;
+    /// let user: StackSid = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// assert_eq!(user.as_sid().registry_classes_subkey(), "S-1-5-21-1-2-3-1001_Classes");
+    /// ```
     #[inline]
-    fn clone(&self) -> Self {
-        self.as_sid().into()
+    #[must_use]
+    pub fn registry_classes_subkey(&self) -> String {
+        let mut key = self.registry_subkey();
+        key.push_str("_Classes");
+        key
     }
+
+    /// Returns the `S-1-...` string form of this SID, like `to_string`, but faster.
+    ///
+    /// The numbers are formatted with `itoa` straight into a `String` allocated
+    /// once with the right capacity, without going through the
+    /// `fmt::Formatter` machinery; the `to_string` benchmark group compares
+    /// both. Authorities that do not fit in a `u32` (written in hexadecimal)
+    /// are rare and go through `Display`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// let sid = well_known::BUILTIN_ADMINISTRATORS.as_sid();
+    /// assert_eq!(sid.to_string_fast(), sid.to_string());
+    /// ```
     #[inline]
-    fn clone_from(&mut self, source: &Self) {
-        if Layout::for_value(self.as_sid()) == Layout::for_value(source.as_sid()) {
-            // Safety: We checked layout is ok
-            unsafe {
-                self.as_binary_mut().copy_from_slice(source.as_binary());
-            }
-        } else {
-            *self = source.clone();
+    #[must_use]
+    pub fn to_string_fast(&self) -> String {
+        let Some(authority) = self.identifier_authority.to_u32() else {
+            return self.to_string();
+        };
+        let sub_authorities = self.get_sub_authorities();
+        // "S-" + revision + "-" + authority, then "-" + up to 10 digits per sub-authority.
+        let mut text = String::with_capacity(16 + 11 * sub_authorities.len());
+        let mut buffer = itoa::Buffer::new();
+        text.push_str("S-");
+        text.push_str(buffer.format(self.revision));
+        text.push('-');
+        text.push_str(buffer.format(authority));
+        for &sub_authority in sub_authorities {
+            text.push('-');
+            text.push_str(buffer.format(sub_authority));
         }
+        text
     }
-}
 
-impl Display for SecurityIdentifier {
+    /// Returns the longest SID shared by `self` and `other`.
+    ///
+    /// The prefix has the common identifier authority and the leading
+    /// sub-authorities on which both SIDs agree. Returns `None` when the
+    /// authorities differ or when the first sub-authorities already differ.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, SecurityIdentifier};
+    /// let alice: SecurityIdentifier = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// let bob: SecurityIdentifier = "S-1-5-21-1-2-3-1002".parse().unwrap();
+    /// assert_eq!(alice.common_prefix(&bob).unwrap(), "S-1-5-21-1-2-3");
+    /// assert!(alice.common_prefix(well_known::WORLD.as_sid()).is_none());
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt(&*self.inner, f)
+    #[must_use]
+    pub fn common_prefix(&self, other: &Self) -> Option<SecurityIdentifier> {
+        if self.identifier_authority != other.identifier_authority {
+            return None;
+        }
+        let sub_authorities = self.get_sub_authorities();
+        let shared = sub_authorities
+            .iter()
+            .zip(other.get_sub_authorities())
+            .take_while(|(left, right)| left == right)
+            .count();
+        SecurityIdentifier::try_new(self.identifier_authority, sub_authorities.get(..shared)?)
     }
-}
-
-impl Eq for SecurityIdentifier {}
 
-impl PartialEq<Sid> for SecurityIdentifier {
-    #[inline]
-    fn eq(&self, other: &Sid) -> bool {
-        AsRef::<Sid>::as_ref(self) == other
-    }
+    /// Returns the SID as a `Vec<u32>` prefixed with its identifier authority,
+    /// or `None` if the authority does not fit in 32 bits.
+    ///
+    /// # Layout
+    /// - index `0`: the identifier authority as a number. Every standard
+    ///   authority fits in 32 bits; wider ones are rejected rather than
+    ///   truncated, so distinct SIDs never produce the same vector.
+    /// - indices `1..`: the sub-authorities, in order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert_eq!(
+    ///     well_known::BUILTIN_ADMINISTRATORS.as_sid().to_u32_vec(),
+    ///     Some(vec![5, 32, 544])
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_u32_vec(&self) -> Option<Vec<u32>> {
+        let [a0, a1, a2, a3, a4, a5] = self.identifier_authority.value;
+        if a0 != 0 || a1 != 0 {
+            return None;
+        }
+        let sub_authorities = self.get_sub_authorities();
+        let mut values = Vec::with_capacity(sub_authorities.len() + 1);
+        values.push(u32::from_be_bytes([a2, a3, a4, a5]));
+        values.extend_from_slice(sub_authorities);
+        Some(values)
+    }
+
+    /// Returns the binary form of this SID with big-endian (network order) sub-authorities.
+    ///
+    /// This differs from [`as_binary`](Self::as_binary), whose sub-authorities are
+    /// little-endian as on Windows. Read it back with
+    /// [`SecurityIdentifier::from_bytes_be`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// let bytes = well_known::BUILTIN_ADMINISTRATORS.as_sid().to_bytes_be();
+    /// assert_eq!(bytes, [1, 2, 0, 0, 0, 0, 0, 5, 0, 0, 0, 32, 0, 0, 2, 32]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.as_binary().to_vec();
+        swap_sub_authority_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Returns a copy of this SID with the sub-authorities in reverse order.
+    ///
+    /// This is a debugging helper to confirm ordering assumptions when chasing
+    /// byte-order bugs: the order of the sub-authorities is reversed, but each
+    /// value is kept as is (no byte swap). The result is not a meaningful SID.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// let reversed = well_known::BUILTIN_ADMINISTRATORS.as_sid().with_reversed_subauthorities();
+    /// assert_eq!(reversed, "S-1-5-544-32");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_reversed_subauthorities(&self) -> SecurityIdentifier {
+        let mut reversed = self.to_owned();
+        reversed.sub_authorities_mut().reverse();
+        reversed
+    }
+}
+
+impl ToOwned for Sid {
+    type Owned = super::SecurityIdentifier;
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        self.into()
+    }
+}
+
+impl Borrow<Sid> for SecurityIdentifier {
+    #[inline]
+    fn borrow(&self) -> &Sid {
+        self.as_sid()
+    }
+}
+
+impl BorrowMut<Sid> for SecurityIdentifier {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut Sid {
+        self.as_sid_mut()
+    }
+}
+
+impl Deref for SecurityIdentifier {
+    type Target = Sid;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_sid()
+    }
+}
+
+impl DerefMut for SecurityIdentifier {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Sid {
+        self.as_sid_mut()
+    }
+}
+
+impl AsRef<Sid> for SecurityIdentifier {
+    #[inline]
+    fn as_ref(&self) -> &Sid {
+        self.as_sid()
+    }
+}
+
+impl AsMut<Sid> for SecurityIdentifier {
+    #[inline]
+    fn as_mut(&mut self) -> &mut Sid {
+        self.as_sid_mut()
+    }
+}
+
+impl Clone for SecurityIdentifier {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.as_sid().into()
+    }
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        if Layout::for_value(self.as_sid()) == Layout::for_value(source.as_sid()) {
+            // Safety: We checked layout is ok
+            unsafe {
+                self.as_binary_mut().copy_from_slice(source.as_binary());
+            }
+        } else {
+            *self = source.clone();
+        }
+    }
+}
+
+impl Display for SecurityIdentifier {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&*self.inner, f)
+    }
+}
+
+impl Eq for SecurityIdentifier {}
+
+impl Hash for SecurityIdentifier {
+    delegate! {
+        to self.as_sid() {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H);
+        }
+    }
+}
+
+impl PartialEq<Sid> for SecurityIdentifier {
+    #[inline]
+    fn eq(&self, other: &Sid) -> bool {
+        AsRef::<Sid>::as_ref(self) == other
+    }
 }
 
 impl PartialEq<SecurityIdentifier> for Sid {
@@ -405,6 +1072,15 @@ impl PartialEq<SecurityIdentifier> for StackSid {
     }
 }
 
+/// Every valid SID fits in a [`StackSid`], so this never fails; the matching
+/// `TryFrom` comes from the blanket impl.
+impl From<SecurityIdentifier> for StackSid {
+    #[inline]
+    fn from(value: SecurityIdentifier) -> Self {
+        Self::from(value.as_sid())
+    }
+}
+
 impl PartialEq for SecurityIdentifier {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -412,17 +1088,58 @@ impl PartialEq for SecurityIdentifier {
     }
 }
 
+impl PartialEq<str> for SecurityIdentifier {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_sid() == other
+    }
+}
+
+impl PartialEq<&str> for SecurityIdentifier {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_sid() == *other
+    }
+}
+
+impl PartialEq<[u8]> for SecurityIdentifier {
+    /// Compares the binary form of this SID (see `as_binary`) with `other`.
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_binary() == other
+    }
+}
+
+impl PartialEq<&[u8]> for SecurityIdentifier {
+    #[inline]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.as_binary() == *other
+    }
+}
+
 impl From<Box<Sid>> for SecurityIdentifier {
     #[inline]
     fn from(value: Box<Sid>) -> Self {
-        Self { inner: value }
+        cfg_if! {
+            if #[cfg(feature = "inline")] {
+                Self { inner: SidStorage::Heap(value) }
+            } else {
+                Self { inner: value }
+            }
+        }
     }
 }
 
 impl From<SecurityIdentifier> for Box<Sid> {
     #[inline]
     fn from(value: SecurityIdentifier) -> Self {
-        value.inner
+        cfg_if! {
+            if #[cfg(feature = "inline")] {
+                value.inner.into_boxed()
+            } else {
+                value.inner
+            }
+        }
     }
 }
 
@@ -523,6 +1240,13 @@ pub mod test {
 
         }
 
+        #[test]
+        fn test_to_boxed_round_trip(sid in arb_security_identifier()){
+            let boxed: Box<Sid> = sid.to_boxed();
+            prop_assert_eq!(boxed.as_binary(), sid.as_binary());
+            prop_assert_eq!(SecurityIdentifier::from(boxed), sid);
+        }
+
         #[test]
         fn test_security_identifier_clone_from(mut sid in arb_security_identifier(), sid_source in arb_security_identifier()){
             sid.clone_from(&sid_source);
@@ -530,6 +1254,32 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn test_clone_from_shrink_then_grow() {
+        let mut sid: SecurityIdentifier = "S-1-5-21-1-2-3-500".parse().unwrap();
+        let short: SecurityIdentifier = "S-1-5-32-544".parse().unwrap();
+        let long: SecurityIdentifier = "S-1-5-21-1-2-3-4-5-6-7-8-9-10-11".parse().unwrap();
+
+        sid.clone_from(&short);
+        assert_eq!(sid, short);
+        sid.clone_from(&long);
+        assert_eq!(sid, long);
+
+        // Same layout: the fast path overwrites the bytes in place.
+        let sibling = long.with_rid_replaced(12);
+        let address = core::ptr::from_ref(sid.as_sid()).addr();
+        sid.clone_from(&sibling);
+        assert_eq!(sid, sibling);
+        assert_eq!(core::ptr::from_ref(sid.as_sid()).addr(), address);
+
+        // `sid.clone_from(&sid)` does not borrow-check, so an equal copy is the
+        // closest safe code can get to a self clone.
+        let copy = sid.clone();
+        sid.clone_from(&copy);
+        assert_eq!(sid, copy);
+        assert_eq!(copy, sibling);
+    }
+
     #[cfg(all(feature = "std", windows))]
     mod windows {
         use core::ptr;
@@ -582,6 +1332,37 @@ pub mod test {
             }
         }
 
+        proptest! {
+            #[test]
+            fn test_init_in_matches_initialize_sid(sid in arb_security_identifier()) {
+                #[repr(C, align(4))]
+                struct Buffer([u8; 68]);
+                let subauth = sid.get_sub_authorities();
+                #[expect(clippy::cast_possible_truncation, reason="No truncation here because of range of subathority is between 1-15")]
+                let n = subauth.len() as u8;
+                let mut win_buffer = Buffer([0; 68]);
+                let win_ptr = win_buffer.0.as_mut_ptr().cast::<core::ffi::c_void>();
+                // SAFETY: The buffer is large enough for any SID and outlives the calls.
+                #[expect(clippy::multiple_unsafe_ops_per_block, reason="Not realy an issue in tests")]
+                let win_bytes = unsafe {
+                    let ok = InitializeSid(
+                        win_ptr,
+                        ptr::from_ref(&sid.identifier_authority).cast::<SID_IDENTIFIER_AUTHORITY>(),
+                        n,
+                    );
+                    prop_assert!(ok != 0, "InitializeSid failed");
+                    for (i, &sa) in subauth.iter().enumerate() {
+                        *GetSidSubAuthority(win_ptr, u32::try_from(i).unwrap()) = sa;
+                    }
+                    slice::from_raw_parts(win_ptr as *const u8, GetLengthSid(win_ptr) as usize)
+                };
+
+                let mut rust_buffer = Buffer([0; 68]);
+                let rust_sid = crate::Sid::init_in(&mut rust_buffer.0, sid.identifier_authority, subauth).unwrap();
+                prop_assert_eq!(rust_sid.as_binary(), win_bytes);
+            }
+        }
+
         #[test]
         fn test_current_sid_work() {
             let result = SecurityIdentifier::get_current_user_sid();
@@ -599,7 +1380,626 @@ pub mod test {
             };
             assert_eq!(result, None, "SID is not valid: {result:?}");
         }
+
+        #[test]
+        fn test_from_explicit_process_token() {
+            use std::os::windows::io::{AsHandle, FromRawHandle, OwnedHandle};
+            use windows_sys::Win32::{
+                Security::TOKEN_QUERY,
+                System::Threading::{GetCurrentProcess, OpenProcessToken},
+            };
+
+            let mut raw_handle = core::ptr::null_mut();
+            // SAFETY: GetCurrentProcess is side-effect free and can be called unconditionally.
+            let process = unsafe { GetCurrentProcess() };
+            // SAFETY: FFI call with a valid out pointer; the result is checked below.
+            let ok = unsafe { OpenProcessToken(process, TOKEN_QUERY, &raw mut raw_handle) };
+            assert_ne!(ok, 0, "OpenProcessToken failed");
+            // SAFETY: OpenProcessToken succeeded, so we own the returned handle.
+            let token = unsafe { OwnedHandle::from_raw_handle(raw_handle) };
+
+            let sid = SecurityIdentifier::from_token(token.as_handle()).unwrap();
+            assert_eq!(sid, SecurityIdentifier::get_current_user_sid().unwrap());
+        }
+
+        #[test]
+        fn test_current_thread_sid_without_impersonation() {
+            let process_sid = SecurityIdentifier::get_current_user_sid().unwrap();
+            let thread_sid = SecurityIdentifier::get_current_thread_user_sid().unwrap();
+            assert_eq!(thread_sid, process_sid);
+        }
+
+        #[test]
+        fn test_current_thread_sid_under_impersonation() {
+            let process_sid = SecurityIdentifier::get_current_user_sid().unwrap();
+            std::thread::spawn(move || {
+                // SAFETY: Impersonating self only affects the current (spawned) thread.
+                let ok = unsafe { ImpersonateSelf(SecurityImpersonation) };
+                assert_ne!(ok, 0, "ImpersonateSelf failed");
+                let thread_sid = SecurityIdentifier::get_current_thread_user_sid();
+                // SAFETY: Reverts the impersonation started above.
+                let reverted = unsafe { RevertToSelf() };
+                assert_ne!(reverted, 0, "RevertToSelf failed");
+                assert_eq!(thread_sid.unwrap(), process_sid);
+            })
+            .join()
+            .unwrap();
+        }
+
+        #[test]
+        fn test_alternate_debug_shows_account() {
+            let sid = SecurityIdentifier::from(crate::well_known::BUILTIN_ADMINISTRATORS.as_sid());
+            let account = sid.lookup_local_sid().unwrap().unwrap().domain_name;
+            let debug = format!("{sid:#?}");
+            assert!(debug.contains(&format!("account: {account}")), "{debug}");
+            assert_eq!(format!("{sid:?}"), "SecurityIdentifier(S-1-5-32-544)");
+        }
+
+        #[cfg(feature = "windows_result")]
+        #[test]
+        fn test_lookup_local_sid_hresult() {
+            let sid = crate::well_known::BUILTIN_ADMINISTRATORS.as_sid();
+            assert_eq!(
+                sid.lookup_local_sid_hresult().unwrap().unwrap(),
+                sid.lookup_local_sid().unwrap().unwrap()
+            );
+        }
+
+        #[test]
+        fn test_as_sid_and_attributes() {
+            /// `SE_GROUP_ENABLED` attribute.
+            const SE_GROUP_ENABLED: u32 = 0x4;
+            let sid = crate::well_known::BUILTIN_ADMINISTRATORS.as_sid();
+            let entry = sid.as_sid_and_attributes(SE_GROUP_ENABLED);
+            assert_eq!(entry.Attributes, SE_GROUP_ENABLED);
+            assert_eq!(entry.Sid, sid.as_raw());
+            // SAFETY: `entry.Sid` borrows `sid`, which is still alive.
+            assert_eq!(unsafe { crate::Sid::from_raw(entry.Sid) }, sid);
+        }
+
+        #[test]
+        fn test_lookup_local_sid_timeout() {
+            use windows_sys::Win32::Foundation::ERROR_TIMEOUT;
+
+            let sid = crate::well_known::BUILTIN_ADMINISTRATORS.as_sid();
+            let lookup = sid
+                .lookup_local_sid_timeout(core::time::Duration::from_secs(30))
+                .unwrap()
+                .unwrap();
+            assert_eq!(lookup, sid.lookup_local_sid().unwrap().unwrap());
+            // A zero timeout may or may not beat the worker, but never fails otherwise.
+            let quick = sid.lookup_local_sid_timeout(core::time::Duration::ZERO);
+            assert!(matches!(
+                quick,
+                Some(Ok(_) | Err(crate::sid_lookup::Error::Other(ERROR_TIMEOUT)))
+            ));
+        }
+
+        #[test]
+        fn test_current_logon_sid_shape() {
+            let logon_sid = SecurityIdentifier::get_current_logon_sid().unwrap();
+            assert!(
+                logon_sid.is_logon_session(),
+                "{logon_sid} is not a logon SID"
+            );
+        }
+
+        #[test]
+        fn test_current_restricting_sids() {
+            // The test process token is not a restricted token.
+            let restricting = SecurityIdentifier::get_current_restricting_sids().unwrap();
+            assert!(restricting.is_empty());
+        }
+
+        #[test]
+        fn test_restricted_token_sids() {
+            use std::os::windows::io::{AsHandle, AsRawHandle, FromRawHandle, OwnedHandle};
+            use windows_sys::Win32::{
+                Security::{
+                    CreateRestrictedToken, SID_AND_ATTRIBUTES, TOKEN_DUPLICATE, TOKEN_QUERY,
+                },
+                System::Threading::{GetCurrentProcess, OpenProcessToken},
+            };
+
+            let mut raw_handle = core::ptr::null_mut();
+            // SAFETY: GetCurrentProcess is side-effect free and can be called unconditionally.
+            let process = unsafe { GetCurrentProcess() };
+            // SAFETY: FFI call with a valid out pointer; the result is checked below.
+            let ok = unsafe {
+                OpenProcessToken(process, TOKEN_DUPLICATE | TOKEN_QUERY, &raw mut raw_handle)
+            };
+            assert_ne!(ok, 0, "OpenProcessToken failed");
+            // SAFETY: OpenProcessToken succeeded, so we own the returned handle.
+            let process_token = unsafe { OwnedHandle::from_raw_handle(raw_handle) };
+
+            let expected = [
+                crate::well_known::WORLD.as_sid(),
+                crate::well_known::BUILTIN_USERS.as_sid(),
+            ];
+            let restricting = expected.map(|sid| SID_AND_ATTRIBUTES {
+                Sid: sid.as_raw(),
+                Attributes: 0,
+            });
+            let mut raw_handle = core::ptr::null_mut();
+            // SAFETY: FFI call with valid pointers; `restricting` points to SIDs
+            // that outlive the call. The result is checked below.
+            let ok = unsafe {
+                CreateRestrictedToken(
+                    process_token.as_raw_handle(),
+                    0,
+                    0,
+                    core::ptr::null(),
+                    0,
+                    core::ptr::null(),
+                    2,
+                    restricting.as_ptr(),
+                    &raw mut raw_handle,
+                )
+            };
+            assert_ne!(ok, 0, "CreateRestrictedToken failed");
+            // SAFETY: CreateRestrictedToken succeeded, so we own the returned handle.
+            let restricted_token = unsafe { OwnedHandle::from_raw_handle(raw_handle) };
+
+            let sids: Vec<SecurityIdentifier> =
+                crate::ext::token_restricted_sids(restricted_token.as_handle()).unwrap();
+            assert_eq!(sids.len(), expected.len());
+            assert!(expected.iter().all(|sid| sids.iter().any(|s| s == *sid)));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_from_cursor() {
+        use std::io::{Cursor, ErrorKind};
+        let admins = well_known::BUILTIN_ADMINISTRATORS;
+        let users = well_known::BUILTIN_USERS;
+        let mut bytes = admins.as_sid().as_binary().to_vec();
+        bytes.extend_from_slice(users.as_sid().as_binary());
+        let mut cursor = Cursor::new(bytes);
+        assert_eq!(SecurityIdentifier::read_from(&mut cursor).unwrap(), admins);
+        assert_eq!(SecurityIdentifier::read_from(&mut cursor).unwrap(), users);
+        let eof = SecurityIdentifier::read_from(&mut cursor).unwrap_err();
+        assert_eq!(eof.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn test_write_binary_read_from_round_trip(sid in arb_security_identifier()) {
+            let mut bytes = Vec::new();
+            sid.write_binary(&mut bytes).unwrap();
+            prop_assert_eq!(bytes.as_slice(), sid.as_binary());
+            let read = SecurityIdentifier::read_from(&mut std::io::Cursor::new(bytes)).unwrap();
+            prop_assert_eq!(read, sid);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[allow(clippy::indexing_slicing, reason = "Fixed-size SID bytes")]
+    fn test_read_from_malformed() {
+        use std::io::{Cursor, ErrorKind};
+        let mut bytes = well_known::BUILTIN_ADMINISTRATORS
+            .as_sid()
+            .as_binary()
+            .to_vec();
+        bytes[0] = 2;
+        let err = SecurityIdentifier::read_from(&mut Cursor::new(&bytes)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        bytes[0] = 1;
+        bytes[1] = 16;
+        let err = SecurityIdentifier::read_from(&mut Cursor::new(&bytes)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let err = SecurityIdentifier::read_from(&mut Cursor::new(&bytes[..1])).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_many_from_cursor() {
+        use std::io::{Cursor, ErrorKind};
+        let sids = [
+            well_known::BUILTIN_ADMINISTRATORS.as_sid(),
+            well_known::LOCAL_SYSTEM.as_sid(),
+            well_known::BUILTIN_USERS.as_sid(),
+        ];
+        let bytes: Vec<u8> = sids
+            .iter()
+            .flat_map(|sid| sid.as_binary())
+            .copied()
+            .collect();
+        let mut cursor = Cursor::new(bytes);
+        let read = SecurityIdentifier::read_many(&mut cursor, 3).unwrap();
+        assert!(read.iter().map(SecurityIdentifier::as_sid).eq(sids));
+        assert_eq!(cursor.position(), 16 + 12 + 16);
+
+        cursor.set_position(0);
+        assert!(
+            SecurityIdentifier::read_many(&mut cursor, 0)
+                .unwrap()
+                .is_empty()
+        );
+        let eof = SecurityIdentifier::read_many(&mut cursor, 4).unwrap_err();
+        assert_eq!(eof.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_from_wide_str() {
+        let wide: Vec<u16> = "S-1-5-32-544".encode_utf16().collect();
+        let sid = SecurityIdentifier::from_wide_str(&wide).unwrap();
+        assert_eq!(sid, well_known::BUILTIN_ADMINISTRATORS);
+        let mut terminated = wide.clone();
+        terminated.push(0);
+        assert_eq!(SecurityIdentifier::from_wide_str(&terminated).unwrap(), sid);
+        let mut unpaired = wide;
+        unpaired.push(0xD800);
+        assert!(SecurityIdentifier::from_wide_str(&unpaired).is_err());
+        assert!(SecurityIdentifier::from_wide_str(&[0x53; 300]).is_err());
     }
+
+    proptest! {
+        #[test]
+        fn test_bytes_be_round_trip(sid in arb_security_identifier()) {
+            let be = sid.to_bytes_be();
+            prop_assert_eq!(be.len(), sid.as_binary().len());
+            prop_assert_eq!(SecurityIdentifier::from_bytes_be(&be).unwrap(), sid);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::indexing_slicing, reason = "Fixed-size SID bytes")]
+    fn test_from_bytes_inferring_count() {
+        let sid: SecurityIdentifier = "S-1-5-21-1-2-3-1001".parse().unwrap();
+        let mut bytes = sid.as_binary().to_vec();
+        for declared in [0, 1, 4, 6, 15, 255] {
+            if let Some(count) = bytes.get_mut(1) {
+                *count = declared;
+            }
+            assert!(SecurityIdentifier::from_bytes(&bytes).is_err());
+            assert_eq!(
+                SecurityIdentifier::from_bytes_inferring_count(&bytes).unwrap(),
+                sid
+            );
+        }
+
+        // The length itself must still be a valid SID size.
+        assert!(SecurityIdentifier::from_bytes_inferring_count(&bytes[..bytes.len() - 1]).is_err());
+        assert!(SecurityIdentifier::from_bytes_inferring_count(&bytes[..8]).is_err());
+        // The revision is still checked.
+        if let Some(revision) = bytes.first_mut() {
+            *revision = 2;
+        }
+        assert!(SecurityIdentifier::from_bytes_inferring_count(&bytes).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::indexing_slicing, reason = "Fixed-size SID bytes")]
+    fn test_bytes_be_differs_from_native() {
+        let sid: SecurityIdentifier = "S-1-5-21-1-2-3-1001".parse().unwrap();
+        let be = sid.to_bytes_be();
+        assert_ne!(be, sid.as_binary());
+        assert_eq!(be.get(..8), sid.as_binary().get(..8));
+        assert_eq!(SecurityIdentifier::from_bytes_be(&be).unwrap(), sid);
+        assert!(SecurityIdentifier::from_bytes_be(&be[..be.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let sid = SecurityIdentifier::try_from("S-1-5-32-544").unwrap();
+        assert_eq!(sid, "S-1-5-32-544".parse::<SecurityIdentifier>().unwrap());
+        assert_eq!(
+            SecurityIdentifier::try_from("not a sid"),
+            Err(crate::InvalidSidFormat)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_to_arc(sid in arb_security_identifier()) {
+            let shared = sid.to_arc();
+            let clone = std::sync::Arc::clone(&shared);
+            prop_assert!(std::sync::Arc::ptr_eq(&shared, &clone));
+            prop_assert_eq!(&*clone, sid.as_sid());
+            prop_assert_eq!(clone.as_binary(), sid.as_binary());
+            prop_assert_eq!(std::sync::Arc::strong_count(&shared), 2);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_to_string_fast_matches_display(sid in arb_security_identifier()) {
+            prop_assert_eq!(sid.to_string_fast(), sid.to_string());
+        }
+    }
+
+    #[test]
+    fn test_to_string_fast_edge_cases() {
+        let max: SecurityIdentifier = "S-1-4294967295-4294967295-4294967295".parse().unwrap();
+        assert_eq!(max.to_string_fast(), max.to_string());
+        let hex = SecurityIdentifier::try_new([0x12, 0x34, 0, 0, 0, 1], [0]).unwrap();
+        assert_eq!(hex.to_string_fast(), "S-1-0x123400000001-0");
+    }
+
+    #[test]
+    fn test_push_sub_authority() {
+        let mut sid = SecurityIdentifier::try_new(
+            crate::SidIdentifierAuthority::NT_AUTHORITY,
+            [21, 1_004_336_348],
+        )
+        .unwrap();
+        sid.push_sub_authority(1001).unwrap();
+        assert_eq!(sid.sub_authority_count, 3);
+        assert_eq!(sid, "S-1-5-21-1004336348-1001");
+
+        let mut full: SecurityIdentifier =
+            "S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15".parse().unwrap();
+        let before = full.clone();
+        assert_eq!(full.push_sub_authority(16), Err(crate::InvalidSidFormat));
+        assert_eq!(full, before);
+    }
+
+    #[test]
+    fn test_pop_sub_authority() {
+        let mut sid: SecurityIdentifier = "S-1-5-21-1004336348-1001".parse().unwrap();
+        assert_eq!(sid.pop_sub_authority(), Some(1001));
+        assert_eq!(sid.sub_authority_count, 2);
+        assert_eq!(sid, "S-1-5-21-1004336348");
+        assert_eq!(sid.pop_sub_authority(), Some(1_004_336_348));
+        assert_eq!(sid, "S-1-5-21");
+
+        // A SID keeps at least one sub-authority.
+        assert_eq!(sid.pop_sub_authority(), None);
+        assert_eq!(sid, "S-1-5-21");
+    }
+
+    proptest! {
+        #[test]
+        fn test_push_pop_round_trip(mut sid in arb_security_identifier(), rid in any::<u32>()) {
+            let before = sid.clone();
+            if sid.push_sub_authority(rid).is_ok() {
+                prop_assert_eq!(sid.pop_sub_authority(), Some(rid));
+                prop_assert_eq!(sid, before);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_push_sub_authority_appends(mut sid in arb_security_identifier(), rid in any::<u32>()) {
+            let before = sid.get_sub_authorities().to_vec();
+            if sid.push_sub_authority(rid).is_ok() {
+                prop_assert_eq!(sid.get_sub_authorities().split_last(), Some((&rid, before.as_slice())));
+            } else {
+                prop_assert_eq!(before.len(), 15);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq_bytes() {
+        let sid: SecurityIdentifier = "S-1-5-32-544".parse().unwrap();
+        let buf = [1u8, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 32, 2, 0, 0, 0xFF];
+        let (exact, _) = buf.split_at(16);
+        assert!(sid == *exact);
+        assert!(sid == exact);
+        assert!(sid != buf.as_slice());
+        assert!(sid != exact.split_at(12).0);
+    }
+
+    #[test]
+    fn test_try_new_checked() {
+        let authority = crate::SidIdentifierAuthority::NT_AUTHORITY;
+        assert_eq!(
+            SecurityIdentifier::try_new_checked(authority, [32, 544]).unwrap(),
+            crate::well_known::BUILTIN_ADMINISTRATORS
+        );
+        assert_eq!(
+            SecurityIdentifier::try_new_checked(authority, []),
+            Err(crate::InvalidSidFormat)
+        );
+        assert_eq!(
+            SecurityIdentifier::try_new_checked(authority, [0; 16]),
+            Err(crate::InvalidSidFormat)
+        );
+        assert!(SecurityIdentifier::try_new_checked(authority, [0; 15]).is_ok());
+    }
+
+    #[test]
+    fn test_from_components_shared() {
+        let components: parsing::SidComponents = "S-1-5-21-1-2-3-500".parse().unwrap();
+        let first = SecurityIdentifier::from_components(&components).unwrap();
+        let second = SecurityIdentifier::from_components(&components).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first, "S-1-5-21-1-2-3-500");
+        assert_eq!(first.to_components(), components);
+
+        let empty = parsing::SidComponents {
+            identifier_authority: [0, 0, 0, 0, 0, 5],
+            sub_authority: arrayvec::ArrayVec::new(),
+        };
+        assert_eq!(
+            SecurityIdentifier::from_components(&empty),
+            Err(crate::InvalidSidFormat)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_into_stack_sid_round_trip(sid in arb_security_identifier()) {
+            let stack = crate::StackSid::from(sid.clone());
+            prop_assert_eq!(&stack, &sid);
+            prop_assert_eq!(&SecurityIdentifier::from(stack.as_sid()), &sid);
+            #[allow(
+                clippy::unnecessary_fallible_conversions,
+                reason = "Checks the blanket TryFrom is available"
+            )]
+            let converted = crate::StackSid::try_from(sid);
+            prop_assert_eq!(converted, Ok(stack));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_with_reversed_subauthorities_twice(sid in arb_security_identifier()) {
+            let reversed = sid.with_reversed_subauthorities();
+            prop_assert_eq!(reversed.sub_authority_count, sid.sub_authority_count);
+            prop_assert_eq!(reversed.get_sub_authorities().first(), sid.get_sub_authorities().last());
+            prop_assert_eq!(reversed.with_reversed_subauthorities(), sid);
+        }
+    }
+
+    #[test]
+    fn test_with_rid_replaced() {
+        let admin: SecurityIdentifier = "S-1-5-21-1004336348-1177238915-682003330-500"
+            .parse()
+            .unwrap();
+        let guest = admin.with_rid_replaced(501);
+        assert_eq!(guest, "S-1-5-21-1004336348-1177238915-682003330-501");
+        assert_eq!(guest.sub_authority_count, admin.sub_authority_count);
+        assert_eq!(admin.rid(), 500);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_os_str() {
+        use std::ffi::OsString;
+        let valid = OsString::from("S-1-5-32-544");
+        assert_eq!(
+            SecurityIdentifier::from_os_str(&valid).unwrap(),
+            well_known::BUILTIN_ADMINISTRATORS
+        );
+        let garbage = OsString::from("S-1-5-x");
+        assert_eq!(
+            SecurityIdentifier::from_os_str(&garbage),
+            Err(crate::InvalidSidFormat)
+        );
+
+        #[cfg(unix)]
+        let invalid = {
+            use std::os::unix::ffi::OsStringExt;
+            OsString::from_vec(b"S-1-5-\xFF".to_vec())
+        };
+        #[cfg(windows)]
+        let invalid = {
+            use std::os::windows::ffi::OsStringExt;
+            let mut wide: Vec<u16> = "S-1-5-".encode_utf16().collect();
+            // Lone surrogate, not representable as UTF-8.
+            wide.push(0xD800);
+            OsString::from_wide(&wide)
+        };
+        #[cfg(any(unix, windows))]
+        assert_eq!(
+            SecurityIdentifier::from_os_str(&invalid),
+            Err(crate::InvalidSidFormat)
+        );
+    }
+
+    #[test]
+    fn test_registry_subkey() {
+        let user: SecurityIdentifier = "S-1-5-21-1004336348-1177238915-682003330-1001"
+            .parse()
+            .unwrap();
+        assert_eq!(user.registry_subkey(), user.to_string());
+        assert_eq!(
+            user.registry_classes_subkey(),
+            "S-1-5-21-1004336348-1177238915-682003330-1001_Classes"
+        );
+        assert_eq!(
+            well_known::LOCAL_SYSTEM.as_sid().registry_subkey(),
+            "S-1-5-18"
+        );
+    }
+
+    #[test]
+    fn test_with_rid_offset() {
+        let admin: SecurityIdentifier = "S-1-5-21-1-2-3-500".parse().unwrap();
+        assert_eq!(admin.with_rid_offset(12).unwrap(), "S-1-5-21-1-2-3-512");
+        assert_eq!(admin.with_rid_offset(-500).unwrap(), "S-1-5-21-1-2-3-0");
+        assert_eq!(admin.with_rid_offset(-501), None);
+        assert_eq!(admin.with_rid_offset(i64::MAX), None);
+
+        let max = admin.with_rid_replaced(u32::MAX);
+        assert_eq!(max.with_rid_offset(1), None);
+        assert_eq!(max.with_rid_offset(0).unwrap(), max);
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        let alice: SecurityIdentifier = "S-1-5-21-1004336348-1177238915-682003330-1001"
+            .parse()
+            .unwrap();
+        let bob: SecurityIdentifier = "S-1-5-21-1004336348-1177238915-682003330-1002"
+            .parse()
+            .unwrap();
+        let domain = alice.common_prefix(&bob).unwrap();
+        assert_eq!(domain, "S-1-5-21-1004336348-1177238915-682003330");
+        assert_eq!(alice.common_prefix(&alice).unwrap(), alice);
+        assert_eq!(alice.common_prefix(&domain).unwrap(), domain);
+
+        let builtin_users = well_known::BUILTIN_USERS;
+        assert!(alice.common_prefix(builtin_users.as_sid()).is_none());
+        assert!(alice.common_prefix(well_known::WORLD.as_sid()).is_none());
+    }
+
+    #[test]
+    fn test_to_u32_vec() {
+        let sid: SecurityIdentifier = "S-1-5-21-1004336348-1177238915-682003330-1001"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            sid.to_u32_vec().unwrap(),
+            [5, 21, 1_004_336_348, 1_177_238_915, 682_003_330, 1001]
+        );
+
+        let wide = crate::SidIdentifierAuthority::new([0, 1, 0, 0, 0, 5]);
+        let wide_sid = SecurityIdentifier::try_new(wide, [21, 1001]).unwrap();
+        assert_eq!(wide_sid.to_u32_vec(), None);
+        let narrow =
+            SecurityIdentifier::try_new(crate::SidIdentifierAuthority::NT_AUTHORITY, [21, 1001])
+                .unwrap();
+        assert_eq!(narrow.to_u32_vec().unwrap(), [5, 21, 1001]);
+    }
+
+    #[test]
+    fn test_u128_round_trip() {
+        let admins = well_known::BUILTIN_ADMINISTRATORS;
+        let packed = admins.as_sid().try_to_u128().unwrap();
+        assert_eq!(SecurityIdentifier::try_from_u128(packed).unwrap(), admins);
+        let sid = SecurityIdentifier::from(admins.as_sid());
+        assert_eq!(sid.try_to_u128(), Some(packed));
+    }
+
+    #[test]
+    fn test_u128_rejects_non_canonical() {
+        let packed = well_known::LOCAL_SYSTEM.as_sid().try_to_u128().unwrap();
+        // Second sub-authority set while the count is 1.
+        assert!(SecurityIdentifier::try_from_u128(packed | (1 << 32)).is_none());
+        // Count of 3.
+        assert!(SecurityIdentifier::try_from_u128(packed + (2 << 112)).is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn test_u128_round_trip_short_sids(sid in arb_security_identifier()) {
+            let packed = sid.try_to_u128();
+            prop_assert_eq!(packed.is_some(), sid.get_sub_authorities().len() <= 2);
+            if let Some(packed) = packed {
+                prop_assert_eq!(SecurityIdentifier::try_from_u128(packed), Some(sid));
+            }
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "sub_authority_count")]
+    fn test_corrupted_count_is_caught() {
+        let mut sid =
+            SecurityIdentifier::try_new(crate::SidIdentifierAuthority::NT_AUTHORITY, [32, 544])
+                .unwrap();
+        sid.as_sid_mut().sub_authority_count = crate::sid::MAX_SUBAUTHORITY_COUNT + 1;
+        let _ = sid.as_binary();
+    }
+
     #[test]
     fn test_debug() {
         let sample_sid = well_known::NULL;
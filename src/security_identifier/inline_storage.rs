@@ -0,0 +1,203 @@
+//! Inline-first storage backing [`SecurityIdentifier`](crate::SecurityIdentifier)
+//! when the `inline` feature is enabled.
+//!
+//! Most SIDs encountered in practice (well-known SIDs, domain accounts
+//! `S-1-5-21-x-y-z-rid`) have at most 5 sub-authorities, so they are kept
+//! inline and only larger ones are boxed.
+
+use super::maybe_uninit::MaybeUninitSecurityIdentifier;
+#[cfg(not(has_ptr_metadata))]
+use crate::polyfills_ptr::{from_raw_parts, from_raw_parts_mut};
+use crate::sid::SID_HEAD_SIZE;
+use crate::{Sid, SidIdentifierAuthority};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use ::alloc::boxed::Box;
+use core::mem::{offset_of, size_of};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+#[cfg(has_ptr_metadata)]
+use core::ptr::{from_raw_parts, from_raw_parts_mut};
+
+/// Maximum number of sub-authorities stored inline before spilling to the heap.
+pub const INLINE_SUB_AUTHORITY_CAPACITY: usize = 5;
+
+/// Fixed-capacity SID with the same header layout as [`Sid`].
+///
+/// Only the first `sub_authority_count` sub-authorities are meaningful.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct InlineSid {
+    revision: u8,
+    sub_authority_count: u8,
+    identifier_authority: SidIdentifierAuthority,
+    sub_authority: [u32; INLINE_SUB_AUTHORITY_CAPACITY],
+}
+
+impl InlineSid {
+    const EMPTY: Self = Self {
+        revision: Sid::REVISION,
+        sub_authority_count: 0,
+        identifier_authority: SidIdentifierAuthority::NULL_AUTHORITY,
+        sub_authority: [0; INLINE_SUB_AUTHORITY_CAPACITY],
+    };
+
+    /// Builds an inline SID from parts, or `None` if it does not fit inline.
+    ///
+    /// The caller is responsible for the `1..=15` length check.
+    pub fn try_new(
+        identifier_authority: SidIdentifierAuthority,
+        sub_authority: &[u32],
+    ) -> Option<Self> {
+        let mut sid = Self::EMPTY;
+        sid.sub_authority
+            .get_mut(..sub_authority.len())?
+            .copy_from_slice(sub_authority);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "Length is bounded by INLINE_SUB_AUTHORITY_CAPACITY"
+        )]
+        let sub_authority_count = sub_authority.len() as u8;
+        sid.sub_authority_count = sub_authority_count;
+        sid.identifier_authority = identifier_authority;
+        Some(sid)
+    }
+
+    /// Copies a binary SID inline, or `None` if it does not fit inline.
+    ///
+    /// The caller is responsible for validating `bytes`.
+    pub const fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() > size_of::<Self>() {
+            return None;
+        }
+        let mut sid = Self::EMPTY;
+        // Safety: `bytes` fits in `Self` and every bit pattern is a valid `InlineSid`.
+        unsafe {
+            ptr::from_mut(&mut sid)
+                .cast::<u8>()
+                .copy_from_nonoverlapping(bytes.as_ptr(), bytes.len());
+        }
+        Some(sid)
+    }
+
+    const fn as_sid(&self) -> &Sid {
+        debug_assert!(
+            self.sub_authority_count as usize <= INLINE_SUB_AUTHORITY_CAPACITY,
+            "sub_authority_count exceeds the inline capacity"
+        );
+        let raw: *const () = ptr::from_ref(self).cast();
+        // SAFETY: The header layout is compatible (`repr(C)`) and the trailing slice
+        // length `sub_authority_count` never exceeds the inline capacity.
+        unsafe { &*from_raw_parts(raw, self.sub_authority_count as usize) }
+    }
+
+    const fn as_sid_mut(&mut self) -> &mut Sid {
+        debug_assert!(
+            self.sub_authority_count as usize <= INLINE_SUB_AUTHORITY_CAPACITY,
+            "sub_authority_count exceeds the inline capacity"
+        );
+        let raw: *mut () = ptr::from_mut(self).cast();
+        // SAFETY: same justification as `as_sid`, but for a mutable reference.
+        unsafe { &mut *from_raw_parts_mut(raw, self.sub_authority_count as usize) }
+    }
+}
+
+/// Storage of a [`SecurityIdentifier`](crate::SecurityIdentifier): inline when small, boxed otherwise.
+pub enum SidStorage {
+    /// Small SID stored inline.
+    Inline(InlineSid),
+    /// SID too large to be stored inline (or adopted from an existing `Box<Sid>`).
+    Heap(Box<Sid>),
+}
+
+impl SidStorage {
+    /// Converts this storage into a `Box<Sid>`, allocating if it is inline.
+    pub fn into_boxed(self) -> Box<Sid> {
+        match self {
+            Self::Inline(inline) => {
+                // Safety: `inline` holds a valid SID.
+                unsafe {
+                    MaybeUninitSecurityIdentifier::boxed_from_bytes(inline.as_sid().as_binary())
+                }
+            }
+            Self::Heap(boxed) => boxed,
+        }
+    }
+}
+
+impl Deref for SidStorage {
+    type Target = Sid;
+
+    fn deref(&self) -> &Sid {
+        match self {
+            Self::Inline(inline) => inline.as_sid(),
+            Self::Heap(boxed) => boxed,
+        }
+    }
+}
+
+impl DerefMut for SidStorage {
+    fn deref_mut(&mut self) -> &mut Sid {
+        match self {
+            Self::Inline(inline) => inline.as_sid_mut(),
+            Self::Heap(boxed) => boxed,
+        }
+    }
+}
+
+impl AsRef<Sid> for SidStorage {
+    fn as_ref(&self) -> &Sid {
+        self
+    }
+}
+
+impl AsMut<Sid> for SidStorage {
+    fn as_mut(&mut self) -> &mut Sid {
+        self
+    }
+}
+
+const _: () = assert!(
+    offset_of!(InlineSid, sub_authority) == SID_HEAD_SIZE,
+    "InlineSid header must match Sid header"
+);
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::{SecurityIdentifier, arb_security_identifier};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn inline_matches_heap(sid in arb_security_identifier()) {
+            let heap: Box<Sid> = sid.clone().into();
+            prop_assert_eq!(heap.as_binary(), sid.as_binary());
+            prop_assert_eq!(&*heap, &*sid);
+            prop_assert_eq!(SecurityIdentifier::from(heap), sid);
+        }
+
+        #[test]
+        fn storage_kind_follows_capacity(sid in arb_security_identifier()) {
+            let is_inline = matches!(sid.inner, SidStorage::Inline(_));
+            prop_assert_eq!(
+                is_inline,
+                sid.get_sub_authorities().len() <= INLINE_SUB_AUTHORITY_CAPACITY
+            );
+        }
+
+        #[test]
+        fn clone_from_across_storage(mut sid in arb_security_identifier(), source in arb_security_identifier()) {
+            sid.clone_from(&source);
+            prop_assert_eq!(sid.as_binary(), source.as_binary());
+        }
+    }
+
+    #[test]
+    fn mutation_through_deref_mut() {
+        let mut sid =
+            SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, [32, 544]).unwrap();
+        sid.identifier_authority = SidIdentifierAuthority::NULL_AUTHORITY;
+        assert_eq!(sid.to_string(), "S-1-0-32-544");
+    }
+}
@@ -1,13 +1,17 @@
 #[cfg(not(has_ptr_metadata))]
-use crate::polyfills_ptr::from_raw_parts_mut;
+use crate::polyfills_ptr::{from_raw_parts, from_raw_parts_mut};
 use crate::{SecurityIdentifier, Sid, SidSizeInfo};
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use ::alloc::{alloc, boxed::Box};
+use ::alloc::{alloc, boxed::Box, sync::Arc};
 #[cfg(has_ptr_metadata)]
-use core::ptr::from_raw_parts_mut;
-use core::{alloc::Layout, mem, ptr::NonNull};
+use core::ptr::{from_raw_parts, from_raw_parts_mut};
+use core::{
+    alloc::Layout,
+    mem::{self, offset_of, size_of},
+    ptr::{self, NonNull},
+};
 #[cfg(feature = "std")]
-use std::alloc;
+use std::{alloc, sync::Arc};
 
 /// Internal helper that owns uninitialized memory for a `Sid`.
 ///
@@ -67,6 +71,15 @@ impl MaybeUninitSecurityIdentifier {
     /// - The caller must guarantee that the `Sid` pointed to by this handle
     ///   has been fully initialized and is a valid `Sid` value.
     pub unsafe fn assume_init(self) -> SecurityIdentifier {
+        // Safety: Same precondition as this method.
+        SecurityIdentifier::from(unsafe { self.assume_init_boxed() })
+    }
+
+    /// Turn this uninitialized handle into a fully initialized `Box<Sid>`.
+    ///
+    /// # Safety
+    /// Same preconditions as [`Self::assume_init`].
+    pub unsafe fn assume_init_boxed(self) -> Box<Sid> {
         // Build the fat pointer before preventing `Drop`.
         let raw_ptr = self.sid_ptr();
         #[expect(clippy::mem_forget, reason = "We will box the raw pointer just after")]
@@ -75,9 +88,71 @@ impl MaybeUninitSecurityIdentifier {
         // SAFETY:
         // - `raw_ptr` comes from `alloc::alloc` with layout `this.layout`.
         // - Ownership is transferred to `Box`, `Drop` will not deallocate.
-        let inner = unsafe { Box::from_raw(raw_ptr) };
+        unsafe { Box::from_raw(raw_ptr) }
+    }
+
+    /// Allocates a `Box<Sid>` holding a copy of `bytes`.
+    ///
+    /// # Safety
+    /// `bytes` must encode a valid SID whose length matches the embedded
+    /// `sub_authority_count`.
+    pub unsafe fn boxed_from_bytes(bytes: &[u8]) -> Box<Sid> {
+        // SAFETY: All safety criteron are described in the doc
+        let size_info = unsafe {
+            #[expect(
+                clippy::indexing_slicing,
+                reason = "It's the unchecked version safety is precised in the doc."
+            )]
+            SidSizeInfo::from_count(bytes[offset_of!(Sid, sub_authority_count)]).unwrap_unchecked()
+        };
+        // Safety: The uninit SID is properly initialized by copying from `bytes` after.
+        let mut uninit = Self::alloc(&size_info);
+        // Safety: We copy all the bytes from a valid SID of the same size.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                uninit.as_mut_ptr().cast::<u8>(),
+                size_info.get_layout().size(),
+            );
+        }
+        // Safety: all is written so we can init.
+        unsafe { uninit.assume_init_boxed() }
+    }
 
-        SecurityIdentifier { inner }
+    /// Allocates an `Arc<Sid>` holding a copy of `bytes`.
+    ///
+    /// `Arc` does not expose its allocation, so the SID is written into an
+    /// `Arc<[u32]>` of the same size and alignment, which is then re-typed
+    /// with the `Sid` metadata.
+    ///
+    /// # Safety
+    /// Same preconditions as [`Self::boxed_from_bytes`].
+    pub unsafe fn arc_from_bytes(bytes: &[u8]) -> Arc<Sid> {
+        // SAFETY: All safety criteron are described in the doc
+        let size_info = unsafe {
+            #[expect(
+                clippy::indexing_slicing,
+                reason = "It's the unchecked version safety is precised in the doc."
+            )]
+            SidSizeInfo::from_count(bytes[offset_of!(Sid, sub_authority_count)]).unwrap_unchecked()
+        };
+        let size = size_info.get_layout().size();
+        let mut words = Arc::<[u32]>::new_uninit_slice(size.div_ceil(size_of::<u32>()));
+        // SAFETY: The `Arc` was just created so it is not shared yet.
+        let uninit = unsafe { Arc::get_mut(&mut words).unwrap_unchecked() };
+        // Safety: `uninit` spans exactly `size` bytes and `bytes` holds a valid SID of that size.
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), uninit.as_mut_ptr().cast::<u8>(), size);
+        }
+        // Safety: all is written so we can init.
+        let words = unsafe { words.assume_init() };
+        let raw: *const Sid = from_raw_parts(
+            Arc::into_raw(words).cast::<()>(),
+            size_info.get_sub_authority_count() as usize,
+        );
+        // SAFETY: `raw` comes from `Arc::<[u32]>::into_raw`; `[u32]` and this `Sid`
+        // have the same size and alignment, and the memory holds a valid SID.
+        unsafe { Arc::from_raw(raw) }
     }
 }
 
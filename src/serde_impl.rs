@@ -1,5 +1,5 @@
 #[cfg(all(windows, feature = "std"))]
-use crate::sid_lookup::DomainAndName;
+use crate::sid_lookup::{DomainAndName, OwnedSidLookup, SidType};
 #[cfg(not(feature = "alloc"))]
 use arrayvec::ArrayString;
 use cfg_if::cfg_if;
@@ -8,13 +8,16 @@ use core::fmt;
 use core::fmt::Write;
 use core::marker::PhantomData;
 use core::str::FromStr;
+#[cfg(all(windows, feature = "std"))]
+use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, de};
 use serde::{Serialize, Serializer, ser};
 
 #[cfg(feature = "alloc")]
 use crate::SecurityIdentifier;
 use crate::StackSid;
-use crate::{ConstSid, Sid, internal::SidLenValid};
+use crate::{ConstSid, OwnedSid, Sid, SidSddl, internal::SidLenValid};
+use core::borrow::Borrow;
 
 impl Serialize for Sid {
     #[inline]
@@ -150,6 +153,52 @@ where
     }
 }
 
+impl<T: Borrow<Sid>> Serialize for SidSddl<T> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sid: &Sid = self.0.borrow();
+        match sid.sddl_alias() {
+            Some(alias) if serializer.is_human_readable() => serializer.serialize_str(alias),
+            _ => sid.serialize(serializer),
+        }
+    }
+}
+
+/// `StackSid` parsed from either an SDDL alias or a regular SID string.
+struct SddlOrSid(StackSid);
+
+impl FromStr for SddlOrSid {
+    type Err = crate::InvalidSidFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StackSid::from_sddl_alias(s)
+            .map_or_else(|| s.parse(), Ok)
+            .map(Self)
+    }
+}
+
+impl TryFrom<&[u8]> for SddlOrSid {
+    type Error = crate::InvalidSidFormat;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        StackSid::try_from(value).map(Self)
+    }
+}
+
+impl<'de, T: OwnedSid> Deserialize<'de> for SidSddl<T> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let SddlOrSid(sid) = deserialize_sid_like(deserializer)?;
+        Ok(Self(T::from(sid.as_sid())))
+    }
+}
+
 #[cfg(all(windows, feature = "std"))]
 impl<'de> Deserialize<'de> for DomainAndName {
     #[inline]
@@ -190,6 +239,36 @@ impl Serialize for DomainAndName {
     }
 }
 
+#[cfg(all(windows, feature = "std"))]
+impl Serialize for SidType {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_i32((*self).into())
+        }
+    }
+}
+
+#[cfg(all(windows, feature = "std"))]
+impl Serialize for OwnedSidLookup {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("OwnedSidLookup", 3)?;
+        state.serialize_field("domain", &self.domain)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("sid_type", &self.sid_type)?;
+        state.end()
+    }
+}
+
 #[cfg(test)]
 mod test {
     const SID: ConstSid<3> =
@@ -220,4 +299,45 @@ mod test {
             &[Token::String("S-1-5-5-32-544")],
         );
     }
+
+    #[test]
+    fn test_sddl_aliased() {
+        let admins = crate::SidSddl(crate::well_known::BUILTIN_ADMINISTRATORS.as_sid());
+        serde_test::assert_ser_tokens(&admins.readable(), &[Token::Str("BA")]);
+        serde_test::assert_ser_tokens(&admins.compact(), &[Token::Bytes(admins.0.as_binary())]);
+        let stack = crate::SidSddl(crate::StackSid::from(admins.0));
+        serde_test::assert_tokens(&stack.clone().readable(), &[Token::Str("BA")]);
+        serde_test::assert_de_tokens(&stack.readable(), &[Token::Str("S-1-5-32-544")]);
+    }
+
+    #[test]
+    fn test_sddl_not_aliased() {
+        let sid = crate::SidSddl(crate::StackSid::from(SID.as_sid()));
+        serde_test::assert_tokens(&sid.clone().readable(), &[Token::String("S-1-5-5-32-544")]);
+        serde_test::assert_tokens(&sid.compact(), &[Token::Bytes(BYTES)]);
+    }
+
+    #[cfg(all(windows, feature = "std"))]
+    #[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+    #[test]
+    fn test_owned_sid_lookup_json() {
+        use crate::sid_lookup::{DomainAndName, OwnedSidLookup, SidLookup};
+
+        let lookup = SidLookup {
+            domain_name: DomainAndName::new("BUILTIN", "Administrators"),
+            sid_type_raw: 4,
+        };
+        let json = serde_json::to_string(&OwnedSidLookup::from(lookup)).unwrap();
+        assert_eq!(
+            json,
+            r#"{"domain":"BUILTIN","name":"Administrators","sid_type":"Alias"}"#
+        );
+
+        let unknown = SidLookup {
+            domain_name: DomainAndName::new("", "Nobody"),
+            sid_type_raw: 42,
+        };
+        let json = serde_json::to_string(&OwnedSidLookup::from(unknown)).unwrap();
+        assert_eq!(json, r#"{"domain":"","name":"Nobody","sid_type":null}"#);
+    }
 }
@@ -17,19 +17,24 @@ pub use windows::sid_lookup;
 use crate::InvalidSidFormat;
 use crate::utils;
 use crate::utils::validate_sid_bytes_unaligned;
+use arrayvec::ArrayString;
+use core::fmt::Write;
+use core::str::FromStr;
+use parsing::SidComponents;
 
 pub use parsing::MAX_SUBAUTHORITY_COUNT;
 pub use parsing::MIN_SUBAUTHORITY_COUNT;
 
 #[cfg(not(has_ptr_metadata))]
-use crate::polyfills_ptr::from_raw_parts;
+use crate::polyfills_ptr::{from_raw_parts, from_raw_parts_mut};
 #[cfg(has_ptr_metadata)]
-use core::ptr::from_raw_parts;
+use core::ptr::{from_raw_parts, from_raw_parts_mut};
 
 use crate::{SidIdentifierAuthority, SidSizeInfo};
 
 use core::{
     alloc::Layout,
+    cmp::Ordering,
     fmt::{self, Debug, Display},
     hash::Hash,
     slice,
@@ -69,6 +74,15 @@ pub struct Sid {
 /// Useful when computing minimal layouts and when manipulating metadata
 /// independently of the dynamic tail.
 #[repr(C)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
 pub struct SidHead {
     pub revision: u8,
     pub sub_authority_count: u8,
@@ -170,6 +184,77 @@ impl Sid {
         }
     }
 
+    /// Returns the sub-authorities as a mutable slice of length `sub_authority_count`.
+    ///
+    /// The slice length is fixed, so RIDs can be edited in place without
+    /// desynchronizing `sub_authority_count` from the trailing data.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{ConstSid, SidIdentifierAuthority};
+    /// let mut const_sid = ConstSid::<2>::new(SidIdentifierAuthority::NT_AUTHORITY, [32, 544]);
+    /// let sid = const_sid.as_sid_mut();
+    /// if let Some(rid) = sid.sub_authorities_mut().last_mut() {
+    ///     *rid = 545;
+    /// }
+    /// assert_eq!(sid.to_string(), "S-1-5-32-545");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn sub_authorities_mut(&mut self) -> &mut [u32] {
+        // Safety: self is valid and fully initialized, and the length matches
+        // `sub_authority_count`.
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.sub_authority.as_mut_ptr(),
+                self.sub_authority_count as usize,
+            )
+        }
+    }
+
+    /// Rewrites `sub_authority_count` to `count` and returns `self` as a SID
+    /// with that many sub-authorities.
+    ///
+    /// This reinterprets a buffer owned by the caller as a shorter SID, e.g. to
+    /// drop trailing RIDs of a buffer filled by FFI without copying it. Only
+    /// the header is written; the sub-authorities keep their values, and the
+    /// returned SID is consistent (header count equals slice length).
+    ///
+    /// Returns `None` (and leaves `self` untouched) if `count` is `0` or
+    /// exceeds the number of sub-authorities covered by `self`.
+    ///
+    /// # Safety
+    /// - The backing memory must hold at least `8 + 4 * count` bytes. This
+    ///   always holds since `count` is bounded by the slice length `self` was
+    ///   created with; the call never reaches beyond that memory.
+    /// - Every other way of reaching this memory must derive the SID length
+    ///   from the header, as a [`StackSid`](crate::StackSid) or a byte buffer
+    ///   filled by [`Sid::init_in`] or FFI does. It must not be owned by a
+    ///   `SecurityIdentifier` or a `Box<Sid>`, whose length is fixed when
+    ///   they are allocated and would then disagree with the header.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::StackSid;
+    /// let mut stack: StackSid = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// // SAFETY: a `StackSid` derives its length from the header.
+    /// let domain = unsafe { stack.as_sid_mut().with_count(4) }.unwrap();
+    /// assert_eq!(domain.to_string(), "S-1-5-21-1-2-3");
+    /// assert_eq!(stack, "S-1-5-21-1-2-3");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const unsafe fn with_count(&mut self, count: u8) -> Option<&mut Self> {
+        if count == 0 || count as usize > self.sub_authority.len() {
+            return None;
+        }
+        self.sub_authority_count = count;
+        let raw: *mut () = core::ptr::from_mut(self).cast();
+        // Safety: `count` does not exceed the tail covered by `self`, and the
+        // header now matches the new slice length.
+        Some(unsafe { &mut *from_raw_parts_mut(raw, count as usize) })
+    }
+
     /// Computes the minimal `Layout` (size + align) needed for **this** instance
     /// given its current `sub_authority_count`.
     ///
@@ -213,6 +298,613 @@ impl Sid {
             unsafe { Self::from_raw_internal(value.as_ptr().cast()) },
         )
     }
+
+    /// Writes a SID into the caller-provided `buf` and returns a reference to it.
+    ///
+    /// This is the allocation-free counterpart of `InitializeSid` followed by
+    /// `GetSidSubAuthority` writes: only the first
+    /// [`binary_len_for(subs.len())`](Self::binary_len_for) bytes of `buf` are
+    /// used, the rest is left untouched.
+    ///
+    /// # Errors
+    /// Returns [`InvalidSidFormat`] if `subs` does not hold `1..=15`
+    /// sub-authorities, if `buf` is too short, or if `buf` is not aligned
+    /// like a `u32`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, Sid, SidIdentifierAuthority};
+    /// #[repr(align(4))]
+    /// struct Buffer([u8; 16]);
+    /// let mut buffer = Buffer([0; 16]);
+    /// let sid = Sid::init_in(&mut buffer.0, SidIdentifierAuthority::NT_AUTHORITY, &[32, 544]).unwrap();
+    /// assert_eq!(sid, well_known::BUILTIN_ADMINISTRATORS.as_sid());
+    /// ```
+    #[inline]
+    pub fn init_in<'a>(
+        buf: &'a mut [u8],
+        authority: SidIdentifierAuthority,
+        subs: &[u32],
+    ) -> Result<&'a mut Self, InvalidSidFormat> {
+        let count = u8::try_from(subs.len()).map_err(|_| InvalidSidFormat)?;
+        let len = Self::binary_len_for(count).ok_or(InvalidSidFormat)?;
+        let buf = buf.get_mut(..len).ok_or(InvalidSidFormat)?;
+        if !buf.as_ptr().addr().is_multiple_of(align_of::<u32>()) {
+            return Err(InvalidSidFormat);
+        }
+
+        let (head, tail) = buf.split_at_mut(SID_HEAD_SIZE);
+        let [a0, a1, a2, a3, a4, a5] = authority.value;
+        head.copy_from_slice(&[Self::REVISION, count, a0, a1, a2, a3, a4, a5]);
+        for (chunk, sub) in tail.chunks_exact_mut(size_of::<u32>()).zip(subs) {
+            chunk.copy_from_slice(&sub.to_ne_bytes());
+        }
+
+        let raw: *mut () = buf.as_mut_ptr().cast();
+        // Safety: `buf` is aligned, exactly as long as a SID with `count`
+        // sub-authorities, and now holds a valid header and tail.
+        Ok(unsafe { &mut *from_raw_parts_mut(raw, subs.len()) })
+    }
+
+    /// Checks the invariants of this already-formed `Sid` without touching the OS.
+    ///
+    /// The checks are:
+    /// - `revision` equals [`Sid::REVISION`],
+    /// - `sub_authority_count` is in `1..=15`,
+    /// - the trailing slice length matches `sub_authority_count`.
+    ///
+    /// This is the reference counterpart of the byte validation done by [`Sid::from_bytes`]
+    /// and is meant to catch corruption of SIDs obtained through `unsafe` or FFI paths.
+    ///
+    /// # Errors
+    /// Returns [`InvalidSidFormat`] if any of the invariants does not hold.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// let mut sid = well_known::BUILTIN_ADMINISTRATORS;
+    /// assert!(sid.as_sid().validate().is_ok());
+    /// sid.revision = 2;
+    /// assert!(sid.as_sid().validate().is_err());
+    /// ```
+    #[inline]
+    pub const fn validate(&self) -> Result<(), InvalidSidFormat> {
+        if self.revision != Self::REVISION
+            || !utils::sub_authority_size_guard(self.sub_authority_count as usize)
+            || self.sub_authority.len() != self.sub_authority_count as usize
+        {
+            return Err(InvalidSidFormat);
+        }
+        Ok(())
+    }
+
+    /// Cheaply checks that `bytes` is as long as its declared sub-authority count requires.
+    ///
+    /// This is a fast-path rejection for wire decoders: it only looks at the
+    /// buffer length and the count byte, not at the revision. A `true` result
+    /// does not mean the SID is valid; use [`Sid::from_bytes`] for full validation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, Sid};
+    /// let bytes = well_known::BUILTIN_USERS.as_sid().as_binary();
+    /// assert!(Sid::plausible_len(bytes));
+    /// assert!(!Sid::plausible_len(&bytes[..bytes.len() - 1]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn plausible_len(bytes: &[u8]) -> bool {
+        utils::sid_bytes_len_matches_count(bytes)
+    }
+
+    /// Returns the binary length of a SID with `count` sub-authorities, or `None`
+    /// if `count` is outside `1..=15`.
+    ///
+    /// Usable in `const` contexts, e.g. to size a buffer without building a SID.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, Sid};
+    /// const ADMINS_LEN: usize = Sid::binary_len_for(2).unwrap();
+    /// assert_eq!(ADMINS_LEN, well_known::BUILTIN_ADMINISTRATORS.as_sid().as_binary().len());
+    /// assert_eq!(Sid::binary_len_for(0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn binary_len_for(count: u8) -> Option<usize> {
+        match SidSizeInfo::from_count(count) {
+            Some(info) => Some(info.get_layout().size()),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if `bytes` is the canonical encoding of a SID.
+    ///
+    /// The encoding is canonical when the revision is `1` and the length matches
+    /// the declared sub-authority count exactly, so padded or truncated blobs
+    /// are rejected. This is the boolean form of the check done by [`Sid::from_bytes`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, Sid};
+    /// let bytes = well_known::BUILTIN_USERS.as_sid().as_binary();
+    /// assert!(Sid::is_canonical_encoding(bytes));
+    /// let mut padded = bytes.to_vec();
+    /// padded.extend_from_slice(&[0; 4]);
+    /// assert!(!Sid::is_canonical_encoding(&padded));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_canonical_encoding(bytes: &[u8]) -> bool {
+        validate_sid_bytes_unaligned(bytes).is_ok()
+    }
+
+    /// Returns a copy of the identifier authority of this [`Sid`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, SidIdentifierAuthority};
+    /// assert_eq!(
+    ///     well_known::LOCAL_SYSTEM.as_sid().authority(),
+    ///     SidIdentifierAuthority::NT_AUTHORITY
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn authority(&self) -> SidIdentifierAuthority {
+        self.identifier_authority
+    }
+
+    /// Splits this [`Sid`] into the [`SidComponents`] used by the parser and the `sid!` macro.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, SidComponents, SidIdentifierAuthority, StackSid};
+    /// let mut components = well_known::BUILTIN_ADMINISTRATORS.as_sid().to_components();
+    /// assert_eq!(components.sub_authority.as_slice(), [32, 544]);
+    /// components.sub_authority[1] = 545;
+    /// let users = StackSid::try_new(
+    ///     SidIdentifierAuthority::new(components.identifier_authority),
+    ///     &components.sub_authority,
+    /// );
+    /// assert_eq!(users.unwrap(), well_known::BUILTIN_USERS);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_components(&self) -> SidComponents {
+        SidComponents {
+            identifier_authority: self.identifier_authority.value,
+            sub_authority: self.get_sub_authorities().iter().copied().collect(),
+        }
+    }
+
+    /// Copies this SID into a [`ConstSid<N>`](crate::ConstSid), or returns `None`
+    /// if it does not have exactly `N` sub-authorities.
+    ///
+    /// This wraps `ConstSid::<N>::try_from`, letting `N` be inferred from the
+    /// binding instead of spelled out with a turbofish.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, ConstSid, SecurityIdentifier};
+    /// let owned: SecurityIdentifier = "S-1-5-32-544".parse().unwrap();
+    /// let admins: Option<ConstSid<2>> = owned.to_const();
+    /// assert_eq!(admins, Some(well_known::BUILTIN_ADMINISTRATORS));
+    /// assert_eq!(owned.to_const::<1>(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_const<const N: usize>(&self) -> Option<crate::ConstSid<N>>
+    where
+        [u32; N]: crate::internal::SidLenValid,
+    {
+        crate::ConstSid::try_from(self).ok()
+    }
+
+    /// Computes the 64-bit FNV-1a hash of the binary form ([`Sid::as_binary`]).
+    ///
+    /// Unlike the [`Hash`] implementation fed to a `DefaultHasher`, the value is
+    /// deterministic: it does not depend on a random seed, the process, the
+    /// platform or the crate version, so it can be persisted or used for sharding.
+    /// Equal SIDs always produce equal values. It is not a cryptographic hash.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert_eq!(well_known::LOCAL_SYSTEM.as_sid().fnv1a_u64(), 0x9013_37f3_80a6_9c32);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn fnv1a_u64(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        let mut hash = OFFSET_BASIS;
+        let mut bytes = self.as_binary();
+        while let Some((&byte, rest)) = bytes.split_first() {
+            hash = (hash ^ byte as u64).wrapping_mul(PRIME);
+            bytes = rest;
+        }
+        hash
+    }
+
+    /// Returns the last sub-authority value (Relative Identifier, or RID) of this [`Sid`].
+    ///
+    /// A valid SID always has at least one sub-authority; `0` is returned otherwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert_eq!(well_known::BUILTIN_ADMINISTRATORS.as_sid().rid(), 544);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rid(&self) -> u32 {
+        match self.get_sub_authorities() {
+            [.., rid] => *rid,
+            [] => 0,
+        }
+    }
+
+    /// Compares two SIDs by their RID (see [`rid`](Self::rid)) only.
+    ///
+    /// The rest of the SID is ignored, so SIDs from different domains with the
+    /// same RID compare equal. Combine with [`core::cmp::Reverse`] or
+    /// [`Ordering::reverse`] to list the highest RIDs first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// use core::cmp::Ordering;
+    /// let admins = well_known::BUILTIN_ADMINISTRATORS.as_sid();
+    /// let users = well_known::BUILTIN_USERS.as_sid();
+    /// assert_eq!(admins.cmp_by_rid(users), Ordering::Less);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn cmp_by_rid(&self, other: &Self) -> Ordering {
+        self.rid().cmp(&other.rid())
+    }
+
+    /// Maps this SID to one of `buckets` shards, as `rid() % buckets`.
+    ///
+    /// The result is stable across versions and platforms. When `buckets` is
+    /// `0`, `0` is returned instead of panicking.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert_eq!(well_known::BUILTIN_ADMINISTRATORS.as_sid().rid_shard(16), 544 % 16);
+    /// assert_eq!(well_known::BUILTIN_ADMINISTRATORS.as_sid().rid_shard(0), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn rid_shard(&self, buckets: u32) -> u32 {
+        match self.rid().checked_rem(buckets) {
+            Some(shard) => shard,
+            None => 0,
+        }
+    }
+
+    /// Returns the relative IDs of this SID, skipping the domain identifier of domain SIDs.
+    ///
+    /// A SID is treated as a domain SID when its authority is NT (`S-1-5`), its
+    /// first sub-authority is `21` and it has at least four sub-authorities; the
+    /// `21-x-y-z` prefix is then skipped and only the trailing RIDs are yielded
+    /// (none for the domain SID itself). For every other SID, all sub-authorities
+    /// are yielded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, StackSid};
+    /// let user: StackSid = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// assert!(user.as_sid().relative_ids().eq([1001]));
+    /// assert!(well_known::BUILTIN_ADMINISTRATORS.as_sid().relative_ids().eq([32, 544]));
+    /// ```
+    #[inline]
+    pub fn relative_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        const SECURITY_NT_NON_UNIQUE: u32 = 21;
+        const DOMAIN_PREFIX_LEN: usize = 4;
+        let sub_authorities = self.get_sub_authorities();
+        let skip = match sub_authorities {
+            [SECURITY_NT_NON_UNIQUE, _, _, _, ..]
+                if self.identifier_authority == SidIdentifierAuthority::NT_AUTHORITY =>
+            {
+                DOMAIN_PREFIX_LEN
+            }
+            _ => 0,
+        };
+        sub_authorities.iter().skip(skip).copied()
+    }
+
+    /// Returns `true` if this is the NULL SID (`S-1-0-0`, [`well_known::NULL`](crate::well_known::NULL)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert!(well_known::NULL.as_sid().is_null());
+    /// assert!(!well_known::WORLD.as_sid().is_null());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        self == crate::well_known::NULL.as_sid()
+    }
+
+    /// Returns `true` if this is the Everyone SID (`S-1-1-0`, [`well_known::WORLD`](crate::well_known::WORLD)).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert!(well_known::EVERYONE.as_sid().is_everyone());
+    /// assert!(!well_known::NULL.as_sid().is_everyone());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_everyone(&self) -> bool {
+        self == crate::well_known::WORLD.as_sid()
+    }
+
+    /// Returns `true` if this is a well-known group SID, such as Everyone
+    /// (`S-1-1-0`), Authenticated Users (`S-1-5-11`) or Interactive (`S-1-5-4`).
+    ///
+    /// The check uses an offline table, so it works on every platform. Unlike
+    /// well-known accounts (e.g. Local System) and BUILTIN aliases, these SIDs
+    /// have the `WellKnownGroup` account type.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert!(well_known::AUTHENTICATED_USERS.as_sid().is_well_known_group());
+    /// assert!(!well_known::LOCAL_SYSTEM.as_sid().is_well_known_group());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_well_known_group(&self) -> bool {
+        crate::well_known::WELL_KNOWN_GROUPS.contains(&self)
+    }
+
+    /// Returns `true` if this is a logon session SID (`S-1-5-5-X-Y`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, StackSid};
+    /// let logon: StackSid = "S-1-5-5-0-123456".parse().unwrap();
+    /// assert!(logon.as_sid().is_logon_session());
+    /// assert!(!well_known::LOCAL_SYSTEM.as_sid().is_logon_session());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_logon_session(&self) -> bool {
+        self.logon_session_id().is_some()
+    }
+
+    /// Returns the `(X, Y)` pair of a logon session SID (`S-1-5-5-X-Y`), or `None` for any other SID.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::StackSid;
+    /// let logon: StackSid = "S-1-5-5-0-123456".parse().unwrap();
+    /// assert_eq!(logon.as_sid().logon_session_id(), Some((0, 123_456)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn logon_session_id(&self) -> Option<(u32, u32)> {
+        const SECURITY_LOGON_IDS_RID: u32 = 5;
+        if !matches!(
+            self.identifier_authority,
+            SidIdentifierAuthority::NT_AUTHORITY
+        ) {
+            return None;
+        }
+        match *self.get_sub_authorities() {
+            [SECURITY_LOGON_IDS_RID, high, low] => Some((high, low)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is a service SID (`S-1-5-80-...`), as used by `NT SERVICE\<name>` accounts.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, StackSid};
+    /// let trusted_installer: StackSid =
+    ///     "S-1-5-80-956008885-3418522649-1831038044-1853292631-2271478464".parse().unwrap();
+    /// assert!(trusted_installer.as_sid().is_service_sid());
+    /// assert!(!well_known::LOCAL_SERVICE.as_sid().is_service_sid());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_service_sid(&self) -> bool {
+        const SECURITY_SERVICE_ID_BASE_RID: u32 = 80;
+        matches!(
+            self.identifier_authority,
+            SidIdentifierAuthority::NT_AUTHORITY
+        ) && matches!(
+            self.get_sub_authorities(),
+            [SECURITY_SERVICE_ID_BASE_RID, ..]
+        )
+    }
+
+    /// Returns `true` if this is an `AppContainer` capability SID (`S-1-15-3-...`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// assert!(well_known::INTERNET_CLIENT.as_sid().is_capability());
+    /// assert!(!well_known::BUILTIN_USERS.as_sid().is_capability());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_capability(&self) -> bool {
+        const SECURITY_CAPABILITY_BASE_RID: u32 = 3;
+        matches!(
+            self.identifier_authority,
+            SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY
+        ) && matches!(
+            self.get_sub_authorities(),
+            [SECURITY_CAPABILITY_BASE_RID, _, ..]
+        )
+    }
+
+    /// Compares the binary form of two SIDs in constant time.
+    ///
+    /// `PartialEq` stops at the first differing byte, so its timing can reveal
+    /// how much of a secret SID (e.g. an allow-list entry) an attacker-supplied
+    /// SID matches. Use this instead when one side is secret; the length, and
+    /// so the sub-authority count, is still compared in variable time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// let admins = well_known::BUILTIN_ADMINISTRATORS.as_sid();
+    /// assert!(bool::from(admins.ct_eq(admins)));
+    /// assert!(!bool::from(admins.ct_eq(well_known::BUILTIN_USERS.as_sid())));
+    /// ```
+    #[cfg(feature = "subtle")]
+    #[inline]
+    #[must_use]
+    pub fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(self.as_binary(), other.as_binary())
+    }
+
+    /// Compares two capability SIDs, treating the sub-authorities after the
+    /// capability base RID as an unordered set.
+    ///
+    /// This is **not** how Windows compares SIDs: the order of sub-authorities
+    /// is always significant there. It only helps when the trailing values of a
+    /// capability SID (`S-1-15-3-...`) are known to be a set. Both SIDs must be
+    /// capability SIDs (see [`is_capability`](Self::is_capability)) with the
+    /// same sub-authorities up to their order, repeats included; any other
+    /// pair falls back to `==`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::StackSid;
+    /// let a: StackSid = "S-1-15-3-1024-1-2-3".parse().unwrap();
+    /// let b: StackSid = "S-1-15-3-1024-3-2-1".parse().unwrap();
+    /// assert!(a.as_sid().eq_capability_set(b.as_sid()));
+    /// assert_ne!(a, b);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_capability_set(&self, other: &Self) -> bool {
+        if !self.is_capability() || !other.is_capability() {
+            return self == other;
+        }
+        let (ours, theirs) = (self.get_sub_authorities(), other.get_sub_authorities());
+        if ours.len() != theirs.len() {
+            return false;
+        }
+        let mut sorted_ours = [0u32; MAX_SUBAUTHORITY_COUNT as usize];
+        let mut sorted_theirs = [0u32; MAX_SUBAUTHORITY_COUNT as usize];
+        let (sorted_ours, _) = sorted_ours.split_at_mut(ours.len());
+        let (sorted_theirs, _) = sorted_theirs.split_at_mut(theirs.len());
+        sorted_ours.copy_from_slice(ours);
+        sorted_theirs.copy_from_slice(theirs);
+        // Both slices share the capability base RID, so the sorted slices are
+        // equal exactly when the trailing values are the same multiset.
+        sorted_ours.sort_unstable();
+        sorted_theirs.sort_unstable();
+        sorted_ours == sorted_theirs
+    }
+
+    /// Compares the canonical string form of this SID with `s`, without allocating.
+    ///
+    /// Unlike `PartialEq<str>`, which parses `s`, this formats `self` into a
+    /// fixed-size stack buffer and compares the text, so it is available
+    /// without `alloc` and only matches the exact output of `Display`
+    /// (e.g. `"s-1-5-18"` does not match).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// let sid = well_known::LOCAL_SYSTEM;
+    /// assert!(sid.as_sid().eq_str("S-1-5-18"));
+    /// assert!(!sid.as_sid().eq_str("S-1-5-19"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_str(&self, s: &str) -> bool {
+        // The longest SID string ("S-1-0x" + 12 hex digits + 15 `-u32`) is 183 bytes.
+        let mut buffer = ArrayString::<256>::new();
+        write!(buffer, "{self}").is_ok() && buffer.as_str() == s
+    }
+
+    /// Packs this SID into a `u128` when it has at most two sub-authorities.
+    ///
+    /// The packed value is a compact map key and can be turned back into a SID
+    /// with `SecurityIdentifier::try_from_u128`.
+    ///
+    /// # Layout
+    /// From the most significant byte to the least significant one:
+    /// - byte `0`: revision,
+    /// - byte `1`: sub-authority count (`1` or `2`),
+    /// - bytes `2..8`: identifier authority, in its binary (big-endian) order,
+    /// - bytes `8..12`: second sub-authority (`0` when absent),
+    /// - bytes `12..16`: first sub-authority.
+    ///
+    /// Returns `None` when the SID has more than two sub-authorities.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, StackSid};
+    /// let packed = well_known::BUILTIN_ADMINISTRATORS.as_sid().try_to_u128();
+    /// assert_eq!(packed, Some(0x0102_0000_0000_0005_0000_0220_0000_0020));
+    /// let domain_user: StackSid = "S-1-5-21-1-2-3-1001".parse().unwrap();
+    /// assert_eq!(domain_user.as_sid().try_to_u128(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn try_to_u128(&self) -> Option<u128> {
+        let (first, second) = match *self.get_sub_authorities() {
+            [first] => (first, 0),
+            [first, second] => (first, second),
+            _ => return None,
+        };
+        let [a0, a1, a2, a3, a4, a5] = self.identifier_authority.value;
+        let [s0, s1, s2, s3] = second.to_be_bytes();
+        let [f0, f1, f2, f3] = first.to_be_bytes();
+        Some(u128::from_be_bytes([
+            self.revision,
+            self.sub_authority_count,
+            a0,
+            a1,
+            a2,
+            a3,
+            a4,
+            a5,
+            s0,
+            s1,
+            s2,
+            s3,
+            f0,
+            f1,
+            f2,
+            f3,
+        ]))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Sid {
+    /// Writes the binary representation of this SID to `writer`.
+    ///
+    /// This is the counterpart of `SecurityIdentifier::read_from`.
+    ///
+    /// # Errors
+    /// Returns any error raised by `writer`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::well_known;
+    /// let mut out = Vec::new();
+    /// well_known::LOCAL_SYSTEM.as_sid().write_binary(&mut out).unwrap();
+    /// assert_eq!(out, well_known::LOCAL_SYSTEM.as_sid().as_binary());
+    /// ```
+    #[inline]
+    pub fn write_binary<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(self.as_binary())
+    }
 }
 
 impl Debug for Sid {
@@ -222,38 +914,121 @@ impl Debug for Sid {
     }
 }
 
-impl Display for Sid {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// How [`Sid::display_with`] writes the identifier authority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AuthorityStyle {
+    /// Decimal when the authority fits in a `u32`, `0x`-prefixed hex otherwise,
+    /// like [`Display`] for [`Sid`].
+    #[default]
+    Auto,
+    /// Always decimal (e.g. `S-1-5-...` or `S-1-281474976710655-...`).
+    Decimal,
+    /// Always `0x`-prefixed uppercase hex (e.g. `S-1-0x5-...`).
+    Hex,
+}
+
+impl Sid {
+    /// Writes the `S-R-I-S-S...` form of this SID to `out`, formatting the authority with `style`.
+    fn write_with_style<W: Write>(&self, out: &mut W, style: AuthorityStyle) -> fmt::Result {
         // Write the revision (should always be 1 in modern SIDs)
-        write!(f, "S-{}", self.revision)?;
-
-        // Identifier Authority: print as decimal if fits in u32, else as hex
-        let mut be_bytes = [0u8; 8];
-        be_bytes[2..].copy_from_slice(self.identifier_authority.value.as_slice());
-        let id_auth_value = u64::from_be_bytes(be_bytes);
-        if id_auth_value <= 0xFFFF_FFFF {
-            write!(f, "-{id_auth_value}")?;
-        } else {
-            write!(f, "-0x{id_auth_value:X}")?;
+        write!(out, "S-{}", self.revision)?;
+
+        // Identifier Authority: by default, print as decimal if fits in u32, else as hex
+        let id_auth_value = self.identifier_authority.as_u64();
+        match style {
+            AuthorityStyle::Auto if id_auth_value <= 0xFFFF_FFFF => {
+                write!(out, "-{id_auth_value}")?;
+            }
+            AuthorityStyle::Decimal => write!(out, "-{id_auth_value}")?,
+            AuthorityStyle::Auto | AuthorityStyle::Hex => write!(out, "-0x{id_auth_value:X}")?,
         }
 
         // SubAuthorities
         for &sub_auth in self.get_sub_authorities() {
-            write!(f, "-{sub_auth}")?;
+            write!(out, "-{sub_auth}")?;
         }
         Ok(())
     }
+
+    /// Returns a [`Display`] adapter writing this SID with the given [`AuthorityStyle`].
+    ///
+    /// [`AuthorityStyle::Auto`] gives the same output as `Display` for `Sid`; the
+    /// other styles keep the authority format fixed regardless of its magnitude.
+    /// Width, fill, alignment and precision are honored like for `Sid`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, AuthorityStyle};
+    /// let system = well_known::LOCAL_SYSTEM.as_sid();
+    /// assert_eq!(system.display_with(AuthorityStyle::Auto).to_string(), "S-1-5-18");
+    /// assert_eq!(system.display_with(AuthorityStyle::Hex).to_string(), "S-1-0x5-18");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn display_with(&self, style: AuthorityStyle) -> impl Display + '_ {
+        StyledSid { sid: self, style }
+    }
+}
+
+/// [`Display`] adapter returned by [`Sid::display_with`].
+struct StyledSid<'a> {
+    sid: &'a Sid,
+    style: AuthorityStyle,
+}
+
+impl Display for StyledSid<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.sid.write_with_style(f, self.style);
+        }
+        // The longest SID (decimal authority, 15 sub-authorities) is below 256 bytes.
+        let mut text = ArrayString::<256>::new();
+        self.sid.write_with_style(&mut text, self.style)?;
+        f.pad(&text)
+    }
+}
+
+impl Display for Sid {
+    /// Honors width, fill, alignment and precision (e.g. `{:<40}`), so SIDs
+    /// can be printed in aligned columns.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.display_with(AuthorityStyle::Auto), f)
+    }
 }
 
 impl PartialEq for Sid {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.as_binary() == other.as_binary()
+        // SIDs with different counts have different lengths: skip building the slices.
+        self.sub_authority_count == other.sub_authority_count
+            && self.as_binary() == other.as_binary()
     }
 }
 
 impl Eq for Sid {}
+
+impl PartialEq<str> for Sid {
+    /// Parses `other` as a SID string and compares it to `self`.
+    ///
+    /// A string that is not a valid SID is never equal.
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        SidComponents::from_str(other).is_ok_and(|components| {
+            self.revision == Self::REVISION
+                && self.identifier_authority.value == components.identifier_authority
+                && self.get_sub_authorities() == components.sub_authority.as_slice()
+        })
+    }
+}
+
+impl PartialEq<&str> for Sid {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
 impl Hash for Sid {
     #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
@@ -309,6 +1084,27 @@ mod tests {
             }
         }
 
+        #[test]
+        fn sid_hash_matches_smart_pointers(sid in arb_security_identifier()) {
+            fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            }
+            let expected = hash_of(sid.as_sid());
+            prop_assert_eq!(hash_of(&sid), expected);
+            prop_assert_eq!(hash_of(&sid.as_sid().to_arc()), expected);
+            prop_assert_eq!(hash_of(&sid.as_sid().to_boxed()), expected);
+            prop_assert_eq!(hash_of(&crate::StackSid::from(sid.as_sid())), expected);
+        }
+
+        #[cfg(feature = "subtle")]
+        #[test]
+        fn sid_ct_eq_agrees_with_eq(sid1 in arb_security_identifier(), sid2 in arb_security_identifier()) {
+            prop_assert!(bool::from(sid1.ct_eq(&sid1)));
+            prop_assert_eq!(bool::from(sid1.ct_eq(&sid2)), sid1 == sid2);
+        }
+
         #[test]
         fn sid_sub_authorities_len(sid in arb_security_identifier()) {
             let subs = sid.get_sub_authorities();
@@ -433,6 +1229,405 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_well_known() {
+        assert_eq!(well_known::NULL.as_sid().validate(), Ok(()));
+        assert_eq!(
+            well_known::BUILTIN_ADMINISTRATORS.as_sid().validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_corrupted_revision() {
+        let mut sid = well_known::LOCAL_SYSTEM;
+        sid.revision = 0;
+        assert_eq!(sid.as_sid().validate(), Err(InvalidSidFormat));
+    }
+
+    #[test]
+    fn test_validate_corrupted_count() {
+        let mut sid = crate::StackSid::from(well_known::BUILTIN_USERS.as_sid());
+        sid.as_sid_mut().sub_authority_count = 0;
+        assert_eq!(sid.as_sid().validate(), Err(InvalidSidFormat));
+
+        // A count above the maximum cannot be stored in a `StackSid`, so build the
+        // oversized SID over a buffer large enough to hold its tail.
+        let oversized = MAX_SUBAUTHORITY_COUNT + 1;
+        let mut buffer = [0u32; 2 + MAX_SUBAUTHORITY_COUNT as usize + 1];
+        buffer[0] = u32::from_ne_bytes([Sid::REVISION, oversized, 0, 0]);
+        // SAFETY: The buffer holds the 8-byte header plus `oversized` sub-authorities.
+        let sid: &Sid =
+            unsafe { &*from_raw_parts(buffer.as_ptr().cast::<()>(), usize::from(oversized)) };
+        assert_eq!(sid.validate(), Err(InvalidSidFormat));
+    }
+
+    #[cfg(feature = "alloc")]
+    proptest! {
+        #[test]
+        fn test_to_components_round_trip(sid in arb_security_identifier()) {
+            let components = sid.to_components();
+            let rebuilt = crate::StackSid::try_new(
+                SidIdentifierAuthority::new(components.identifier_authority),
+                &components.sub_authority,
+            )
+            .unwrap();
+            prop_assert_eq!(rebuilt.as_sid(), &*sid);
+            prop_assert_eq!(SidComponents::from_str(&sid.to_string()).unwrap(), components);
+        }
+    }
+
+    #[test]
+    fn test_init_in() {
+        #[repr(C, align(4))]
+        struct Buffer([u8; 72]);
+        let mut buffer = Buffer([0xAA; 72]);
+        let authority = SidIdentifierAuthority::NT_AUTHORITY;
+
+        let sid = Sid::init_in(&mut buffer.0, authority, &[32, 544]).unwrap();
+        assert_eq!(sid, well_known::BUILTIN_ADMINISTRATORS.as_sid());
+        assert_eq!(sid.validate(), Ok(()));
+        assert!(buffer.0.iter().skip(16).all(|&byte| byte == 0xAA));
+
+        let subs = [7; 15];
+        let sid = Sid::init_in(&mut buffer.0, authority, &subs).unwrap();
+        assert_eq!(sid.get_sub_authorities(), subs);
+
+        assert!(Sid::init_in(&mut buffer.0, authority, &[]).is_err());
+        assert!(Sid::init_in(&mut buffer.0, authority, &[1; 16]).is_err());
+        let (_, short) = buffer.0.split_at_mut(56);
+        assert!(Sid::init_in(short, authority, &[1, 2]).is_ok());
+        assert!(Sid::init_in(short, authority, &[1, 2, 3]).is_err());
+        let (_, misaligned) = buffer.0.split_at_mut(1);
+        assert!(Sid::init_in(misaligned, authority, &[1]).is_err());
+    }
+
+    #[test]
+    fn test_eq_capability_set() {
+        let parse = |text: &str| text.parse::<crate::StackSid>().unwrap();
+        let a = parse("S-1-15-3-1024-10-20-30");
+        let reordered = parse("S-1-15-3-1024-30-10-20");
+        assert!(a.as_sid().eq_capability_set(reordered.as_sid()));
+        assert!(reordered.as_sid().eq_capability_set(a.as_sid()));
+        assert!(a.as_sid().eq_capability_set(a.as_sid()));
+
+        let different = parse("S-1-15-3-1024-10-20-31");
+        assert!(!a.as_sid().eq_capability_set(different.as_sid()));
+        let shorter = parse("S-1-15-3-1024-10-20");
+        assert!(!a.as_sid().eq_capability_set(shorter.as_sid()));
+        let repeated = parse("S-1-15-3-1024-10-10-30");
+        assert!(!a.as_sid().eq_capability_set(repeated.as_sid()));
+
+        // Outside capabilities, the order matters as usual.
+        let package = parse("S-1-15-2-1-2-3");
+        let package_reordered = parse("S-1-15-2-3-2-1");
+        assert!(
+            !package
+                .as_sid()
+                .eq_capability_set(package_reordered.as_sid())
+        );
+        assert!(package.as_sid().eq_capability_set(package.as_sid()));
+    }
+
+    #[test]
+    fn test_binary_len_for() {
+        for count in MIN_SUBAUTHORITY_COUNT..=MAX_SUBAUTHORITY_COUNT {
+            assert_eq!(Sid::binary_len_for(count), Some(8 + 4 * usize::from(count)));
+        }
+        assert_eq!(Sid::binary_len_for(0), None);
+        assert_eq!(Sid::binary_len_for(MAX_SUBAUTHORITY_COUNT + 1), None);
+        assert_eq!(
+            Sid::binary_len_for(1),
+            Some(well_known::LOCAL_SYSTEM.as_sid().as_binary().len())
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[allow(clippy::indexing_slicing, reason = "Fixed-size SID bytes")]
+    fn test_is_canonical_encoding() {
+        let bytes = well_known::BUILTIN_ADMINISTRATORS.as_sid().as_binary();
+        assert!(Sid::is_canonical_encoding(bytes));
+        // Trailing padding, even zeroed, is not canonical.
+        let mut padded = bytes.to_vec();
+        padded.extend_from_slice(&[0; 4]);
+        assert!(!Sid::is_canonical_encoding(&padded));
+        padded.truncate(bytes.len() - 1);
+        assert!(!Sid::is_canonical_encoding(&padded));
+        let mut revision_2 = bytes.to_vec();
+        revision_2[0] = 2;
+        assert!(!Sid::is_canonical_encoding(&revision_2));
+        assert!(!Sid::is_canonical_encoding(&[]));
+    }
+
+    #[test]
+    fn test_fnv1a_u64_is_stable() {
+        // Reference values of the FNV-1a specification over the binary SID.
+        assert_eq!(
+            well_known::BUILTIN_ADMINISTRATORS.as_sid().fnv1a_u64(),
+            0x915d_e849_7ce8_170b
+        );
+        assert_eq!(
+            well_known::LOCAL_SYSTEM.as_sid().fnv1a_u64(),
+            0x9013_37f3_80a6_9c32
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    proptest! {
+        #[test]
+        fn test_fnv1a_u64_eq(sid in arb_security_identifier()) {
+            let copy = crate::StackSid::from(&*sid);
+            prop_assert_eq!(sid.fnv1a_u64(), copy.as_sid().fnv1a_u64());
+        }
+    }
+
+    #[test]
+    fn test_sub_authorities_mut_last_rid() {
+        let mut owned: SecurityIdentifier = "S-1-5-21-1-2-3-500".parse().unwrap();
+        *owned.sub_authorities_mut().last_mut().unwrap() = 501;
+        assert_eq!(owned.to_string(), "S-1-5-21-1-2-3-501");
+        assert_eq!(owned.sub_authority_count, 5);
+
+        let mut stack = crate::StackSid::from(well_known::BUILTIN_ADMINISTRATORS.as_sid());
+        *stack.sub_authorities_mut().last_mut().unwrap() = 545;
+        assert_eq!(stack, well_known::BUILTIN_USERS);
+    }
+
+    #[test]
+    fn test_with_count_shorter_view() {
+        let mut stack: crate::StackSid = "S-1-5-21-1-2-3-500".parse().unwrap();
+        // SAFETY: A `StackSid` derives its length from the header.
+        let sid = unsafe { stack.as_sid_mut().with_count(6) };
+        assert!(sid.is_none());
+        // SAFETY: Same as above.
+        let sid = unsafe { stack.as_sid_mut().with_count(0) };
+        assert!(sid.is_none());
+        assert_eq!(stack, "S-1-5-21-1-2-3-500");
+
+        // SAFETY: Same as above.
+        let sid = unsafe { stack.as_sid_mut().with_count(2) }.unwrap();
+        assert_eq!(sid.get_sub_authorities(), [21, 1]);
+        assert_eq!(sid.sub_authority.len(), 2);
+        assert_eq!(sid.validate(), Ok(()));
+        assert_eq!(stack, "S-1-5-21-1");
+        assert_eq!(stack.as_sid().validate(), Ok(()));
+        assert_eq!(stack.as_sid().as_binary().len(), 16);
+    }
+
+    #[test]
+    fn test_eq_different_counts() {
+        let users = well_known::BUILTIN_USERS;
+        let system = well_known::LOCAL_SYSTEM;
+        assert_ne!(users.as_sid(), system.as_sid());
+        // Same prefix, one sub-authority less.
+        let truncated = crate::StackSid::try_new(users.identifier_authority, &[32]).unwrap();
+        assert_ne!(users.as_sid(), truncated.as_sid());
+        assert_eq!(users.as_sid(), users.as_sid());
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let sid = well_known::BUILTIN_ADMINISTRATORS.as_sid();
+        assert_eq!(format!("{sid:<16}|"), "S-1-5-32-544    |");
+        assert_eq!(format!("{sid:>16}|"), "    S-1-5-32-544|");
+        assert_eq!(format!("{sid:*^16}"), "**S-1-5-32-544**");
+        assert_eq!(format!("{sid:.5}"), "S-1-5");
+        // Narrower widths never truncate.
+        assert_eq!(format!("{sid:4}"), "S-1-5-32-544");
+        let owned = SecurityIdentifier::from(sid);
+        assert_eq!(format!("{owned:<14}|"), "S-1-5-32-544  |");
+        let stack = crate::StackSid::from(sid);
+        assert_eq!(format!("{stack:>14}"), "  S-1-5-32-544");
+        assert_eq!(
+            format!("{:>14}", well_known::BUILTIN_ADMINISTRATORS),
+            "  S-1-5-32-544"
+        );
+    }
+
+    #[test]
+    fn test_authority() {
+        let owned: SecurityIdentifier = "S-1-16-12288".parse().unwrap();
+        assert_eq!(owned.authority(), SidIdentifierAuthority::from_u32_be(16));
+        let stack = crate::StackSid::from(well_known::WORLD.as_sid());
+        assert_eq!(
+            stack.authority(),
+            SidIdentifierAuthority::SECURITY_WORLD_AUTHORITY
+        );
+        assert_eq!(well_known::NULL.as_sid().authority().to_u32(), Some(0));
+    }
+
+    #[test]
+    fn test_cmp_by_rid_descending() {
+        let mut sids: Vec<SecurityIdentifier> = [
+            "S-1-5-21-1-2-3-1001",
+            "S-1-5-21-9-9-9-500",
+            "S-1-5-32-544",
+            "S-1-5-21-1-2-3-1105",
+        ]
+        .into_iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        sids.sort_by(|a, b| b.cmp_by_rid(a));
+        let rids: Vec<u32> = sids.iter().map(|sid| sid.rid()).collect();
+        assert_eq!(rids, [1105, 1001, 544, 500]);
+        assert_eq!(
+            well_known::LOCAL_SYSTEM
+                .as_sid()
+                .cmp_by_rid(well_known::LOCAL_SYSTEM.as_sid()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_rid_shard() {
+        let shard = |rid: u32, buckets: u32| {
+            crate::StackSid::try_new(SidIdentifierAuthority::NT_AUTHORITY, &[21, rid])
+                .unwrap()
+                .as_sid()
+                .rid_shard(buckets)
+        };
+        assert_eq!(shard(0, 7), 0);
+        assert_eq!(shard(1001, 0), 0);
+        assert_eq!(shard(1001, 1), 0);
+        assert_eq!(shard(1001, 10), 1);
+        assert_eq!(shard(u32::MAX, 0), 0);
+        assert_eq!(shard(u32::MAX, u32::MAX), 0);
+        assert_eq!(shard(u32::MAX, 2), 1);
+        assert_eq!(shard(u32::MAX - 1, u32::MAX), u32::MAX - 1);
+    }
+
+    #[test]
+    fn test_is_null_is_everyone() {
+        assert!(well_known::NULL.as_sid().is_null());
+        assert!(!well_known::NULL.as_sid().is_everyone());
+        assert!(well_known::WORLD.as_sid().is_everyone());
+        assert!(!well_known::WORLD.as_sid().is_null());
+
+        let parsed: SecurityIdentifier = "S-1-1-0".parse().unwrap();
+        assert!(parsed.is_everyone());
+        // Same sub-authority, other authority.
+        let other: SecurityIdentifier = "S-1-2-0".parse().unwrap();
+        assert!(!other.is_null());
+        assert!(!other.is_everyone());
+        let longer: SecurityIdentifier = "S-1-0-0-0".parse().unwrap();
+        assert!(!longer.is_null());
+    }
+
+    #[test]
+    fn test_is_capability() {
+        assert!(well_known::INTERNET_CLIENT.as_sid().is_capability());
+        let custom: SecurityIdentifier = "S-1-15-3-1024-1065365936-1281604716".parse().unwrap();
+        assert!(custom.is_capability());
+        // Package SIDs (S-1-15-2-...) and the bare base RID are not capabilities.
+        let package: SecurityIdentifier = "S-1-15-2-1".parse().unwrap();
+        assert!(!package.is_capability());
+        let base: SecurityIdentifier = "S-1-15-3".parse().unwrap();
+        assert!(!base.is_capability());
+        let other_authority: SecurityIdentifier = "S-1-5-3-1".parse().unwrap();
+        assert!(!other_authority.is_capability());
+    }
+
+    #[test]
+    fn test_logon_session() {
+        let logon =
+            crate::StackSid::try_new(SidIdentifierAuthority::NT_AUTHORITY, &[5, 0, 0x3E7]).unwrap();
+        assert!(logon.as_sid().is_logon_session());
+        assert_eq!(logon.as_sid().logon_session_id(), Some((0, 0x3E7)));
+
+        let not_nt = crate::StackSid::try_new(
+            SidIdentifierAuthority::SECURITY_WORLD_AUTHORITY,
+            &[5, 0, 0x3E7],
+        )
+        .unwrap();
+        assert!(!not_nt.as_sid().is_logon_session());
+        let too_long =
+            crate::StackSid::try_new(SidIdentifierAuthority::NT_AUTHORITY, &[5, 0, 0x3E7, 1])
+                .unwrap();
+        assert_eq!(too_long.as_sid().logon_session_id(), None);
+        assert!(!well_known::LOCAL_SYSTEM.as_sid().is_logon_session());
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let admins = well_known::BUILTIN_ADMINISTRATORS;
+        let stack = crate::StackSid::from(admins.as_sid());
+        assert!(admins.as_sid() == "S-1-5-32-544");
+        assert_eq!(admins, "S-1-5-32-544");
+        assert_eq!(stack, "S-1-5-32-544");
+        assert_ne!(admins, "S-1-5-32-545");
+        assert_ne!(stack, "S-1-5-32");
+        assert_ne!(admins, "S-1-5-32-544x");
+        assert_ne!(admins, "");
+        #[cfg(feature = "alloc")]
+        {
+            let owned = SecurityIdentifier::from(admins.as_sid());
+            assert_eq!(owned, "S-1-5-32-544");
+            assert_ne!(owned, "not a sid");
+        }
+    }
+
+    #[test]
+    fn test_validate_length_mismatch() {
+        let sid = well_known::BUILTIN_USERS;
+        // SAFETY: The metadata is smaller than the real tail, so the reference stays in bounds.
+        let truncated: &Sid =
+            unsafe { &*from_raw_parts(core::ptr::from_ref(&sid).cast::<()>(), 1) };
+        assert_eq!(truncated.validate(), Err(InvalidSidFormat));
+    }
+
+    #[cfg(feature = "alloc")]
+    proptest! {
+        #[test]
+        fn validate_accepts_generated(sid in arb_security_identifier()) {
+            prop_assert_eq!(sid.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_relative_ids() {
+        let user: crate::StackSid = "S-1-5-21-1004336348-1177238915-682003330-1001"
+            .parse()
+            .unwrap();
+        assert!(user.as_sid().relative_ids().eq([1001]));
+        let domain: crate::StackSid = "S-1-5-21-1-2-3".parse().unwrap();
+        assert_eq!(domain.as_sid().relative_ids().count(), 0);
+        let short: crate::StackSid = "S-1-5-21-1-2".parse().unwrap();
+        assert!(short.as_sid().relative_ids().eq([21, 1, 2]));
+        let other_authority: crate::StackSid = "S-1-3-21-1-2-3-4".parse().unwrap();
+        assert!(other_authority.as_sid().relative_ids().eq([21, 1, 2, 3, 4]));
+        assert!(
+            well_known::BUILTIN_ADMINISTRATORS
+                .as_sid()
+                .relative_ids()
+                .eq([32, 544])
+        );
+    }
+
+    #[test]
+    fn test_display_with() {
+        let small = well_known::LOCAL_SYSTEM.as_sid();
+        let authority = SidIdentifierAuthority::from_u64(0x1000_0000_000A).unwrap();
+        let wide = crate::StackSid::try_new(authority, &[7]).unwrap();
+        let cases = [
+            (AuthorityStyle::Auto, "S-1-5-18", "S-1-0x10000000000A-7"),
+            (AuthorityStyle::Decimal, "S-1-5-18", "S-1-17592186044426-7"),
+            (AuthorityStyle::Hex, "S-1-0x5-18", "S-1-0x10000000000A-7"),
+        ];
+        for (style, expected_small, expected_wide) in cases {
+            assert_eq!(small.display_with(style).to_string(), expected_small);
+            assert_eq!(wide.as_sid().display_with(style).to_string(), expected_wide);
+            assert_eq!(
+                format!("[{:>12}]", small.display_with(style)),
+                format!("[{expected_small:>12}]")
+            );
+        }
+        assert_eq!(
+            small.display_with(AuthorityStyle::default()).to_string(),
+            small.to_string()
+        );
+    }
+
     #[test]
     fn test_debug() {
         let sample_sid = well_known::NULL;
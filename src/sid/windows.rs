@@ -1,11 +1,15 @@
+use core::time::Duration;
 use std::ffi::OsStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
 use widestring::WideCString;
-use windows_sys::Win32::Security::PSID;
+use windows_sys::Win32::Foundation::ERROR_TIMEOUT;
+use windows_sys::Win32::Security::{PSID, SID_AND_ATTRIBUTES};
 pub mod sid_lookup;
 
 #[cfg(windows)]
 use crate::sid::sid_lookup::SidLookup;
-use crate::sid::sid_lookup::{SidLookupOperation, SidType};
+use crate::sid::sid_lookup::{SidLookupFull, SidLookupOperation, SidType};
 
 use super::Sid;
 
@@ -29,6 +33,20 @@ impl Sid {
         core::ptr::from_ref(self) as PSID
     }
 
+    /// Builds a `SID_AND_ATTRIBUTES` pointing to this SID, e.g. for token groups or ACL building.
+    ///
+    /// The returned struct holds a raw pointer borrowed from `self`: the SID
+    /// must outlive every use of it, and must not be moved or dropped while
+    /// the struct is handed to the system.
+    #[inline]
+    #[must_use]
+    pub const fn as_sid_and_attributes(&self, attributes: u32) -> SID_AND_ATTRIBUTES {
+        SID_AND_ATTRIBUTES {
+            Sid: self.as_raw(),
+            Attributes: attributes,
+        }
+    }
+
     // -------- Internals -----------------------------------------------------
 
     /// Convert `OsStr` to `WideCString`, returning `None` on interior-nul errors.
@@ -43,7 +61,7 @@ impl Sid {
     fn is_known_impl(&self, machine: Option<&WideCString>) -> bool {
         // If SidLookupOperation::new() is already the cheap probe,
         // keep it; otherwise we could introduce a dedicated `exists()` in the future.
-        SidLookupOperation::new(self, machine).is_some()
+        matches!(SidLookupOperation::new(self, machine), Some(Ok(_)))
     }
 
     /// Internal: full lookup on a given machine.
@@ -54,7 +72,7 @@ impl Sid {
         machine: Option<&WideCString>,
     ) -> Option<Result<SidLookup, sid_lookup::Error>> {
         // Build once, then process. Keeps the public API tiny.
-        SidLookupOperation::new(self, machine).map(SidLookupOperation::process)
+        SidLookupOperation::new(self, machine).map(|op| op.and_then(SidLookupOperation::process))
     }
 
     // -------- Public API ----------------------------------------------------
@@ -83,6 +101,64 @@ impl Sid {
         self.lookup_impl(None)
     }
 
+    /// Performs a lookup of this SID on the local machine, giving up after `timeout`.
+    ///
+    /// The lookup runs on a worker thread; when it does not finish in time,
+    /// `Some(Err(Error::Other(ERROR_TIMEOUT)))` is returned. The underlying
+    /// `LookupAccountSidW` call cannot be cancelled: it keeps running in the
+    /// background until it completes, and its result is then discarded.
+    ///
+    /// # Panics
+    /// Resumes the panic of the worker thread, if any.
+    #[inline]
+    #[must_use]
+    pub fn lookup_local_sid_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Option<Result<SidLookup, sid_lookup::Error>> {
+        let (sender, receiver) = mpsc::channel();
+        let sid = crate::SecurityIdentifier::from(self);
+        let worker = thread::spawn(move || {
+            // The receiver is gone after a timeout: nobody waits for the result anymore.
+            let _ = sender.send(sid.lookup_local_sid());
+        });
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Some(Err(sid_lookup::Error::Other(ERROR_TIMEOUT))),
+            Err(RecvTimeoutError::Disconnected) => match worker.join() {
+                Err(payload) => std::panic::resume_unwind(payload),
+                // The worker always sends before exiting normally.
+                Ok(()) => Some(Err(sid_lookup::Error::Other(ERROR_TIMEOUT))),
+            },
+        }
+    }
+
+    /// Performs a lookup of this SID on the local machine, reporting failures as a
+    /// [`windows_result::Error`].
+    ///
+    /// Same as [`lookup_local_sid`](Self::lookup_local_sid), with the error
+    /// converted through `From<Error> for windows_result::Error`.
+    #[cfg(feature = "windows_result")]
+    #[inline]
+    #[must_use]
+    pub fn lookup_local_sid_hresult(&self) -> Option<windows_result::Result<SidLookup>> {
+        self.lookup_local_sid()
+            .map(|result| result.map_err(windows_result::Error::from))
+    }
+
+    /// Performs a lookup of this SID on the local machine through `LsaLookupSids2`.
+    ///
+    /// Unlike [`lookup_local_sid`](Self::lookup_local_sid), the result also
+    /// holds the SID of the domain the account belongs to.
+    ///
+    /// # Errors
+    /// Returns an [`Error`](sid_lookup::Error) if the policy cannot be opened or
+    /// the SID is not mapped ([`NoneMapped`](sid_lookup::Error::NoneMapped)).
+    #[inline]
+    pub fn lookup_local_sid_full(&self) -> Result<SidLookupFull, sid_lookup::Error> {
+        sid_lookup::lookup_local_full(self)
+    }
+
     /// Performs a lookup of this SID on a remote machine.
     ///
     /// Accepts any `AsRef<OsStr>` to be ergonomic for callers.
@@ -105,7 +181,9 @@ impl Sid {
         &self,
     ) -> Option<Result<SidType, num_enum::TryFromPrimitiveError<SidType>>> {
         // Avoid re-allocating buffers: rely on the “new()” probe that already gathers raw type.
-        SidLookupOperation::new(self, None).map(|op| SidType::try_from(op.sid_type_raw))
+        SidLookupOperation::new(self, None)?
+            .ok()
+            .map(|op| SidType::try_from(op.sid_type_raw))
     }
 
     /// Returns the `SidType` for this SID on a remote machine (if lookup succeeds).
@@ -116,7 +194,9 @@ impl Sid {
         machine_name: S,
     ) -> Option<Result<SidType, num_enum::TryFromPrimitiveError<SidType>>> {
         Self::osstr_to_wide(machine_name.as_ref()).and_then(|w| {
-            SidLookupOperation::new(self, Some(&w)).map(|op| SidType::try_from(op.sid_type_raw))
+            SidLookupOperation::new(self, Some(&w))?
+                .ok()
+                .map(|op| SidType::try_from(op.sid_type_raw))
         })
     }
 }
@@ -5,9 +5,16 @@ pub mod domain_and_name;
 pub use domain_and_name::DomainAndName;
 mod sid_lookup_operation;
 pub(super) use sid_lookup_operation::SidLookupOperation;
+mod lsa_lookup_operation;
+mod name_lookup_operation;
+pub use lsa_lookup_operation::SidLookupFull;
+pub(in crate::sid) use lsa_lookup_operation::lookup_local as lookup_local_full;
+mod remote_sid_resolver;
+pub use remote_sid_resolver::RemoteSidResolver;
 pub mod error;
 pub use error::Error;
 /// This struct represent the result of a [SID lookup operation](https://learn.microsoft.com/windows/win32/api/winbase/nf-winbase-lookupaccountsidw).
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SidLookup {
     /// The domain and name associated with the SID.
     pub domain_name: DomainAndName,
@@ -23,4 +30,64 @@ impl SidLookup {
     pub fn sid_type(&self) -> Result<SidType, TryFromPrimitiveError<SidType>> {
         SidType::try_from_primitive(self.sid_type_raw)
     }
+
+    /// Get the SID type as an enum, mapping unknown raw values to [`SidType::Unknown`].
+    ///
+    /// Unlike [`SidLookup::sid_type`], this never fails, which keeps callers working
+    /// when newer Windows versions introduce new `SID_NAME_USE` values.
+    #[inline]
+    #[must_use]
+    pub fn sid_type_or_unknown(&self) -> SidType {
+        self.sid_type().unwrap_or(SidType::Unknown)
+    }
+}
+
+/// Owned, serializable view of a [`SidLookup`] with its parts decoded.
+///
+/// Handy when emitting lookup results as JSON (with the `serde` feature).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedSidLookup {
+    /// The domain part, lossily converted to UTF-8.
+    pub domain: String,
+    /// The account name part, lossily converted to UTF-8.
+    pub name: String,
+    /// The decoded SID type, `None` if the raw value is unknown.
+    pub sid_type: Option<SidType>,
+}
+
+impl From<SidLookup> for OwnedSidLookup {
+    #[inline]
+    fn from(value: SidLookup) -> Self {
+        Self {
+            domain: value.domain_name.domain.to_string_lossy().into_owned(),
+            name: value.domain_name.name.to_string_lossy().into_owned(),
+            sid_type: SidType::try_from(value.sid_type_raw).ok(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sid_type_or_unknown_maps_out_of_range() {
+        let lookup = SidLookup {
+            domain_name: DomainAndName::new("NT AUTHORITY", "SYSTEM"),
+            sid_type_raw: 5,
+        };
+        assert_eq!(lookup.sid_type_or_unknown(), SidType::WellKnownGroup);
+
+        for raw in [0, -1, 42, i32::MAX] {
+            let lookup = SidLookup {
+                domain_name: DomainAndName::default(),
+                sid_type_raw: raw,
+            };
+            assert!(
+                lookup.sid_type().is_err(),
+                "{raw} should not be a known SidType"
+            );
+            assert_eq!(lookup.sid_type_or_unknown(), SidType::Unknown);
+        }
+    }
 }
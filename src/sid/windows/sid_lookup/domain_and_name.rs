@@ -4,232 +4,69 @@
 //! - `Display` prints as `DOMAIN\Name` using `to_string_lossy()`.
 //! - `FromStr` parses with the default policy (exactly one `\`).
 //! - Optional validation is controlled by a lightweight `ParsePolicy`.
+//!
+//! The string parsing and validation live in the `no_std`
+//! [`crate::domain_and_name`] module and are re-exported here.
 
 use core::{
     fmt::{self, Display},
     str::FromStr,
 };
 use std::ffi::{OsStr, OsString};
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use windows_sys::Win32::Globalization::{LCMAP_UPPERCASE, LCMapStringEx, LOCALE_NAME_INVARIANT};
 
-use thiserror::Error;
-
-/// Which component an error refers to.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum Component {
-    Domain,
-    Name,
-}
-
-impl Display for Component {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Domain => f.write_str("domain"),
-            Self::Name => f.write_str("name"),
-        }
-    }
-}
-
-/// Parsing/validation errors for `DOMAIN\Name`.
-#[derive(Debug, Clone, PartialEq, Eq, Error)]
-pub enum DomainParsingError {
-    /// Input did not contain a `\` separator.
-    #[error("Missing '\\' separator")]
-    MissingSeparator,
-
-    /// Input contained more than one `\` separator.
-    #[error("Too many '\\' separators")]
-    TooManySeparators,
+pub use crate::domain_and_name::{Component, DomainAndNameRef, DomainParsingError, ParsePolicy};
 
-    /// Left part is empty while policy forbids it.
-    #[error("Domain is empty")]
-    EmptyDomain,
-
-    /// Right part is empty while policy forbids it.
-    #[error("Name is empty")]
-    EmptyName,
-
-    /// A component exceeded the configured maximum length.
-    #[error("{which} too long: max={max}, actual={actual}")]
-    ComponentTooLong {
-        which: Component,
-        max: usize,
-        actual: usize,
-    },
-
-    /// A forbidden code unit/byte was found (e.g., `\` or NUL).
-    #[error("Forbidden code unit 0x{unit:02X} in {which} at index {index}")]
-    ForbiddenUnit {
-        which: Component,
-        unit: u32,
-        index: usize,
-    },
-}
-
-/// Simple, const-friendly validation policy.
-/// Validation itself happens at runtime to keep things straightforward.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct ParsePolicy {
-    /// Allow empty domain (e.g., `\User`)?
-    pub allow_empty_domain: bool,
-    /// Allow empty name (e.g., `DOMAIN\`)?
-    pub allow_empty_name: bool,
-    /// Optional max *byte/count* for each component:
-    /// - Windows: count is in UTF-16 code units
-    /// - Unix: count is in raw bytes
-    pub max_component_len: Option<usize>,
-    /// ASCII code points disallowed in components (default: backslash and NUL).
-    /// They are checked as:
-    /// - Windows: UTF-16 code units equal to `u8` value (e.g. `b'\\' as u16`)
-    /// - Unix: raw bytes
-    pub forbidden_ascii: &'static [u8],
+/// Validate both components of an `OsStr` pair against `policy`.
+fn validate_pair(
+    policy: &ParsePolicy,
+    domain: &OsStr,
+    name: &OsStr,
+) -> Result<(), DomainParsingError> {
+    policy.validate_units(Component::Domain, domain.encode_wide())?;
+    policy.validate_units(Component::Name, name.encode_wide())?;
+    Ok(())
 }
 
-impl ParsePolicy {
-    /// Sensible default for Windows-style `DOMAIN\Name`.
-    pub const DEFAULT: Self = Self {
-        allow_empty_domain: true,
-        allow_empty_name: false,
-        max_component_len: None,
-        forbidden_ascii: b"\\\0",
+/// Uppercases `value` like Windows does for case-insensitive names.
+///
+/// Uses the invariant-locale mapping of `LCMapStringEx`, and falls back to
+/// ASCII uppercasing if the call fails.
+fn to_uppercase(value: &OsStr) -> OsString {
+    let units: Vec<u16> = value.encode_wide().collect();
+    let Ok(len) = i32::try_from(units.len()) else {
+        return value.to_ascii_uppercase();
     };
-
-    /// Const constructor for convenience.
-    #[inline]
-    #[must_use]
-    pub const fn new(
-        allow_empty_domain: bool,
-        allow_empty_name: bool,
-        max_component_len: Option<usize>,
-        forbidden_ascii: &'static [u8],
-    ) -> Self {
-        Self {
-            allow_empty_domain,
-            allow_empty_name,
-            max_component_len,
-            forbidden_ascii,
-        }
-    }
-
-    /// Validate a single component against this policy (runtime, cross‑platform).
-    pub(super) fn validate_component(
-        &self,
-        which: Component,
-        s: &OsStr,
-    ) -> Result<(), DomainParsingError> {
-        if s.is_empty() {
-            return match which {
-                Component::Domain => {
-                    if self.allow_empty_domain {
-                        Ok(())
-                    } else {
-                        Err(DomainParsingError::EmptyDomain)
-                    }
-                }
-                Component::Name => {
-                    if self.allow_empty_name {
-                        Ok(())
-                    } else {
-                        Err(DomainParsingError::EmptyName)
-                    }
-                }
-            };
-        }
-
-        // Length limit (platform-specific notion of "length")
-        if let Some(max) = self.max_component_len {
-            let len = platform_len(s);
-            if len > max {
-                return Err(DomainParsingError::ComponentTooLong {
-                    which,
-                    max,
-                    actual: len,
-                });
-            }
-        }
-
-        // Forbidden units (ASCII set, checked as code units / bytes)
-        platform_forbidden_check(self, which, s)?;
-
-        Ok(())
-    }
-
-    /// Validate both components (runtime).
-    pub(super) fn validate_pair(
-        &self,
-        domain: &OsStr,
-        name: &OsStr,
-    ) -> Result<(), DomainParsingError> {
-        self.validate_component(Component::Domain, domain)?;
-        self.validate_component(Component::Name, name)?;
-        Ok(())
-    }
-}
-
-impl Default for ParsePolicy {
-    #[inline]
-    fn default() -> Self {
-        Self::DEFAULT
-    }
-}
-
-/// Platform-specific length:
-/// - Windows: number of UTF-16 code units
-/// - Unix: number of raw bytes
-fn platform_len(s: &OsStr) -> usize {
-    #[cfg(windows)]
-    {
-        use std::os::windows::ffi::OsStrExt;
-        s.encode_wide().count()
+    if len == 0 {
+        return OsString::new();
     }
-    #[cfg(not(windows))]
-    {
-        use std::os::unix::ffi::OsStrExt;
-        s.as_bytes().len()
-    }
-}
-
-/// Platform-specific forbidden check:
-/// - Windows: iterate UTF-16; match against ASCII `forbidden_ascii` (cast to u16)
-/// - Unix: iterate raw bytes; match against ASCII `forbidden_ascii`
-fn platform_forbidden_check(
-    policy: &ParsePolicy,
-    which: Component,
-    s: &OsStr,
-) -> Result<(), DomainParsingError> {
-    #[cfg(windows)]
-    {
-        use std::os::windows::ffi::OsStrExt;
-        for (idx, unit) in s.encode_wide().enumerate() {
-            // NUL always forbidden (0), plus any ASCII units in policy
-            if unit == 0 || policy.forbidden_ascii.iter().any(|&b| unit == u16::from(b)) {
-                return Err(DomainParsingError::ForbiddenUnit {
-                    which,
-                    unit: u32::from(unit),
-                    index: idx,
-                });
-            }
-        }
-        Ok(())
-    }
-    #[cfg(not(windows))]
-    {
-        use std::os::unix::ffi::OsStrExt;
-        for (idx, &b) in s.as_bytes().iter().enumerate() {
-            if policy.forbidden_ascii.contains(&b) {
-                return Err(DomainParsingError::ForbiddenUnit {
-                    which,
-                    unit: u32::from(b),
-                    index: idx,
-                });
-            }
-        }
-        Ok(())
+    let mut upper = vec![0u16; units.len()];
+    // SAFETY: Both buffers hold `len` units and outlive the call.
+    let written = unsafe {
+        LCMapStringEx(
+            LOCALE_NAME_INVARIANT,
+            LCMAP_UPPERCASE,
+            units.as_ptr(),
+            len,
+            upper.as_mut_ptr(),
+            len,
+            core::ptr::null(),
+            core::ptr::null(),
+            0,
+        )
+    };
+    if written == len {
+        OsString::from_wide(&upper)
+    } else {
+        value.to_ascii_uppercase()
     }
 }
 
 /// Runtime-friendly pair (`OsString`) with `Display`/`FromStr`.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+///
+/// Ordering compares the domain, then the name, in `OsStr` order (case-sensitive).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
 pub struct DomainAndName {
     /// The domain part (before the `\`).
     pub domain: OsString,
@@ -258,7 +95,7 @@ impl DomainAndName {
     ) -> Result<Self, DomainParsingError> {
         let d_os: OsString = domain.into();
         let n_os: OsString = name.into();
-        policy.validate_pair(d_os.as_os_str(), n_os.as_os_str())?;
+        validate_pair(policy, d_os.as_os_str(), n_os.as_os_str())?;
         Ok(Self {
             domain: d_os,
             name: n_os,
@@ -270,15 +107,74 @@ impl DomainAndName {
     /// See [`DomainParsingError`] and [`ParsePolicy`].
     #[inline]
     pub fn parse_with_policy(policy: &ParsePolicy, s: &str) -> Result<Self, DomainParsingError> {
-        // Split into at most 3 parts to detect "too many separators"
-        let mut iter = s.splitn(3, '\\');
+        DomainAndNameRef::parse_with_policy(policy, s).map(Self::from)
+    }
+
+    /// Encodes as UTF-16 `DOMAIN\Name` (no terminating NUL), without lossy conversion.
+    ///
+    /// Suitable for IPC with Windows-native consumers; see [`DomainAndName::from_wide`].
+    #[inline]
+    #[must_use]
+    pub fn to_wide(&self) -> Vec<u16> {
+        self.domain
+            .encode_wide()
+            .chain(core::iter::once(u16::from(b'\\')))
+            .chain(self.name.encode_wide())
+            .collect()
+    }
+
+    /// Decodes UTF-16 `DOMAIN\Name` with the default policy, preserving every code unit
+    /// (including unpaired surrogates).
+    ///
+    /// # Errors
+    /// See [`DomainParsingError`] and [`ParsePolicy::DEFAULT`].
+    #[inline]
+    pub fn from_wide(units: &[u16]) -> Result<Self, DomainParsingError> {
+        let mut iter = units.splitn(3, |&unit| unit == u16::from(b'\\'));
         let domain = iter.next().ok_or(DomainParsingError::MissingSeparator)?;
         let name = iter.next().ok_or(DomainParsingError::MissingSeparator)?;
         if iter.next().is_some() {
             return Err(DomainParsingError::TooManySeparators);
         }
-        policy.validate_pair(OsStr::new(domain), OsStr::new(name))?;
-        Ok(Self::new(domain, name))
+        Self::try_new_with_policy(
+            &ParsePolicy::DEFAULT,
+            OsString::from_wide(domain),
+            OsString::from_wide(name),
+        )
+    }
+
+    /// Returns a copy with the domain uppercased, e.g. to build cache keys.
+    ///
+    /// Domains are case-insensitive on Windows, so `contoso\alice` and
+    /// `CONTOSO\alice` normalize to the same value. The name is kept as is;
+    /// see [`DomainAndName::normalized_with`] to uppercase it too.
+    #[inline]
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        self.normalized_with(false)
+    }
+
+    /// Returns a copy with the domain, and the name if `uppercase_name` is set, uppercased.
+    ///
+    /// The invariant-locale mapping of Windows is used, with an ASCII fallback.
+    #[inline]
+    #[must_use]
+    pub fn normalized_with(&self, uppercase_name: bool) -> Self {
+        Self {
+            domain: to_uppercase(&self.domain),
+            name: if uppercase_name {
+                to_uppercase(&self.name)
+            } else {
+                self.name.clone()
+            },
+        }
+    }
+}
+
+impl From<DomainAndNameRef<'_>> for DomainAndName {
+    #[inline]
+    fn from(value: DomainAndNameRef<'_>) -> Self {
+        Self::new(value.domain, value.name)
     }
 }
 
@@ -351,6 +247,75 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn normalized_uppercases_domain() {
+        let lower: DomainAndName = "contoso\\alice".parse().unwrap();
+        let upper: DomainAndName = "CONTOSO\\alice".parse().unwrap();
+        assert_ne!(lower, upper);
+        assert_eq!(lower.normalized(), upper.normalized());
+        assert_eq!(lower.normalized().to_string(), "CONTOSO\\alice");
+        assert_eq!(lower.normalized_with(true).to_string(), "CONTOSO\\ALICE");
+        assert_eq!(
+            DomainAndName::new("société", "x").normalized().domain,
+            OsString::from("SOCIÉTÉ")
+        );
+    }
+
+    #[test]
+    fn sorts_by_domain_then_name() {
+        let mut accounts: Vec<DomainAndName> = [
+            "NT AUTHORITY\\SYSTEM",
+            "BUILTIN\\Users",
+            "BUILTIN\\Administrators",
+            "ACME\\zoe",
+        ]
+        .iter()
+        .map(|text| text.parse().unwrap())
+        .collect();
+        accounts.sort();
+        let sorted: Vec<String> = accounts.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            sorted,
+            [
+                "ACME\\zoe",
+                "BUILTIN\\Administrators",
+                "BUILTIN\\Users",
+                "NT AUTHORITY\\SYSTEM"
+            ]
+        );
+    }
+
+    #[test]
+    fn wide_round_trip_preserves_unpaired_surrogate() {
+        // "ACME" \ "j<U+D800>hn": the lone high surrogate is not valid UTF-8.
+        let name = OsString::from_wide(&[0x6A, 0xD800, 0x68, 0x6E]);
+        let value = DomainAndName::new("ACME", name.clone());
+        let wide = value.to_wide();
+        assert_eq!(
+            wide,
+            [0x41, 0x43, 0x4D, 0x45, 0x5C, 0x6A, 0xD800, 0x68, 0x6E]
+        );
+        let decoded = DomainAndName::from_wide(&wide).unwrap();
+        assert_eq!(decoded, value);
+        assert_eq!(decoded.name, name);
+    }
+
+    #[test]
+    fn wide_separators() {
+        assert_eq!(
+            DomainAndName::from_wide(&[0x41, 0x42]),
+            Err(DomainParsingError::MissingSeparator)
+        );
+        assert_eq!(
+            DomainAndName::from_wide(&[0x41, 0x5C, 0x42, 0x5C, 0x43]),
+            Err(DomainParsingError::TooManySeparators)
+        );
+        assert_eq!(
+            DomainAndName::from_wide(&[0x41, 0x5C]),
+            Err(DomainParsingError::EmptyName)
+        );
+    }
+
     #[test]
     fn max_len_and_forbidden_ascii() {
         const P: ParsePolicy = ParsePolicy::new(true, false, Some(5), b"\\\0/");
@@ -1,8 +1,9 @@
 use core::num::NonZeroU32;
+use std::io;
 
 use windows_sys::Win32::Foundation::{
-    ERROR_ACCESS_DENIED, ERROR_INVALID_PARAMETER, ERROR_INVALID_SID, ERROR_NO_SUCH_DOMAIN,
-    ERROR_NONE_MAPPED, ERROR_TRUSTED_DOMAIN_FAILURE,
+    ERROR_ACCESS_DENIED, ERROR_BAD_NETPATH, ERROR_INVALID_PARAMETER, ERROR_INVALID_SID,
+    ERROR_NO_SUCH_DOMAIN, ERROR_NONE_MAPPED, ERROR_TRUSTED_DOMAIN_FAILURE,
 };
 
 /// Errors that can be returned by `LookupAccountSidW`.
@@ -43,3 +44,94 @@ impl From<NonZeroU32> for Error {
         }
     }
 }
+
+impl Error {
+    /// Returns the Win32 error code behind this error.
+    #[inline]
+    #[must_use]
+    pub const fn code(self) -> u32 {
+        match self {
+            Self::InvalidSid => ERROR_INVALID_SID,
+            Self::InvalidParameter => ERROR_INVALID_PARAMETER,
+            Self::NoneMapped => ERROR_NONE_MAPPED,
+            Self::AccessDenied => ERROR_ACCESS_DENIED,
+            Self::NetworkPathNotFound => ERROR_BAD_NETPATH,
+            Self::NoSuchDomain => ERROR_NO_SUCH_DOMAIN,
+            Self::TrustedRelationshipFailure => ERROR_TRUSTED_DOMAIN_FAILURE,
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl From<Error> for io::Error {
+    /// Maps the lookup error to the closest [`io::ErrorKind`].
+    ///
+    /// The Win32 code is kept as the inner OS error, reachable through
+    /// [`io::Error::get_ref`].
+    #[inline]
+    fn from(value: Error) -> Self {
+        let kind = match value {
+            Error::NoneMapped | Error::NoSuchDomain => io::ErrorKind::NotFound,
+            Error::AccessDenied | Error::TrustedRelationshipFailure => {
+                io::ErrorKind::PermissionDenied
+            }
+            Error::NetworkPathNotFound => io::ErrorKind::HostUnreachable,
+            Error::InvalidSid | Error::InvalidParameter => io::ErrorKind::InvalidInput,
+            Error::Other(_) => io::ErrorKind::Other,
+        };
+        Self::new(kind, Self::from_raw_os_error(value.code().cast_signed()))
+    }
+}
+
+#[cfg(feature = "windows_result")]
+impl From<Error> for windows_result::Error {
+    /// Wraps the Win32 code of the lookup error into an `HRESULT`.
+    #[inline]
+    fn from(value: Error) -> Self {
+        Self::from_hresult(windows_result::HRESULT::from_win32(value.code()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_error_kinds() {
+        let cases = [
+            (Error::NoneMapped, io::ErrorKind::NotFound),
+            (Error::NoSuchDomain, io::ErrorKind::NotFound),
+            (Error::AccessDenied, io::ErrorKind::PermissionDenied),
+            (Error::NetworkPathNotFound, io::ErrorKind::HostUnreachable),
+            (Error::InvalidSid, io::ErrorKind::InvalidInput),
+            (Error::Other(1234), io::ErrorKind::Other),
+        ];
+        for (error, kind) in cases {
+            assert_eq!(io::Error::from(error).kind(), kind, "{error:?}");
+        }
+    }
+
+    #[test]
+    fn io_error_keeps_win32_code() {
+        for error in [Error::NoneMapped, Error::Other(1234)] {
+            let io_error = io::Error::from(error);
+            let inner = io_error.get_ref().unwrap().downcast_ref::<io::Error>();
+            let code = inner.and_then(io::Error::raw_os_error).unwrap();
+            assert_eq!(code.cast_unsigned(), error.code());
+            assert_eq!(Error::from(NonZeroU32::new(error.code()).unwrap()), error);
+        }
+    }
+
+    #[cfg(feature = "windows_result")]
+    #[test]
+    fn windows_result_keeps_win32_code() {
+        for error in [Error::NoneMapped, Error::AccessDenied, Error::Other(1234)] {
+            let converted = windows_result::Error::from(error);
+            assert_eq!(
+                converted.code(),
+                windows_result::HRESULT::from_win32(error.code())
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! SID lookups through `LsaLookupSids2`, which also report the referenced domain SID.
+
+use core::num::NonZeroU32;
+use core::ptr::{null, null_mut};
+use core::slice;
+use std::ffi::OsString;
+use std::os::windows::ffi::OsStringExt;
+
+use windows_sys::Win32::Foundation::NTSTATUS;
+use windows_sys::Win32::Security::Authentication::Identity::{
+    LSA_HANDLE, LSA_OBJECT_ATTRIBUTES, LSA_REFERENCED_DOMAIN_LIST, LSA_TRANSLATED_NAME,
+    LSA_UNICODE_STRING, LsaClose, LsaFreeMemory, LsaLookupSids2, LsaNtStatusToWinError,
+    LsaOpenPolicy, POLICY_LOOKUP_NAMES,
+};
+
+use super::{Error, SidType};
+use crate::{SecurityIdentifier, Sid};
+
+/// Result of a [`Sid::lookup_local_sid_full`] lookup.
+///
+/// Unlike [`SidLookup`](super::SidLookup), it also carries the SID of the
+/// domain the account belongs to, which is handy to build a domain → SID cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidLookupFull {
+    /// The account name.
+    pub name: OsString,
+    /// The name of the domain the account belongs to.
+    pub domain: OsString,
+    /// The SID of that domain, `None` if LSA did not report one.
+    pub domain_sid: Option<SecurityIdentifier>,
+    /// The decoded SID type, `None` if the raw value is unknown.
+    pub sid_type: Option<SidType>,
+}
+
+/// Maps a failed `NTSTATUS` to the Win32 based [`Error`].
+fn status_to_error(status: NTSTATUS) -> Error {
+    // Safety: `LsaNtStatusToWinError` is always safe to call.
+    let code = unsafe { LsaNtStatusToWinError(status) };
+    NonZeroU32::new(code).map_or(Error::Other(code), Error::from)
+}
+
+/// Local LSA policy handle, closed on drop.
+struct Policy(LSA_HANDLE);
+
+impl Policy {
+    fn open_local() -> Result<Self, Error> {
+        let attributes = LSA_OBJECT_ATTRIBUTES::default();
+        let mut handle: LSA_HANDLE = 0;
+        // Safety: A null system name targets the local machine and `attributes` is zeroed as required.
+        let status = unsafe {
+            LsaOpenPolicy(
+                null(),
+                &raw const attributes,
+                POLICY_LOOKUP_NAMES.cast_unsigned(),
+                &raw mut handle,
+            )
+        };
+        if status < 0 {
+            return Err(status_to_error(status));
+        }
+        Ok(Self(handle))
+    }
+}
+
+impl Drop for Policy {
+    fn drop(&mut self) {
+        // Safety: The handle was opened by `LsaOpenPolicy` and is closed only once.
+        unsafe {
+            LsaClose(self.0);
+        }
+    }
+}
+
+/// Buffer allocated by LSA, freed on drop.
+struct LsaBuffer<T>(*mut T);
+
+impl<T> Drop for LsaBuffer<T> {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // Safety: The buffer was allocated by LSA and is freed only once.
+            unsafe {
+                LsaFreeMemory(self.0.cast());
+            }
+        }
+    }
+}
+
+/// Copies an `LSA_UNICODE_STRING` into an `OsString`.
+///
+/// # Safety
+/// `value.Buffer` must be null or point to `value.Length` readable bytes.
+unsafe fn to_os_string(value: &LSA_UNICODE_STRING) -> OsString {
+    if value.Buffer.is_null() {
+        return OsString::new();
+    }
+    // `Length` is in bytes, without terminating NUL.
+    let len = usize::from(value.Length >> 1);
+    // Safety: Same precondition as this function.
+    OsString::from_wide(unsafe { slice::from_raw_parts(value.Buffer, len) })
+}
+
+pub(in crate::sid) fn lookup_local(sid: &Sid) -> Result<SidLookupFull, Error> {
+    let policy = Policy::open_local()?;
+    let sids = [sid.as_raw()];
+    let mut domains: *mut LSA_REFERENCED_DOMAIN_LIST = null_mut();
+    let mut names: *mut LSA_TRANSLATED_NAME = null_mut();
+    // Safety: `policy` is open, `sids` holds one valid SID and the out pointers are valid.
+    let status = unsafe {
+        LsaLookupSids2(
+            policy.0,
+            0,
+            1,
+            sids.as_ptr(),
+            &raw mut domains,
+            &raw mut names,
+        )
+    };
+    let domains = LsaBuffer(domains);
+    let names = LsaBuffer(names);
+    if status < 0 {
+        return Err(status_to_error(status));
+    }
+
+    // Safety: On success LSA returns one translated name per input SID.
+    let translated = unsafe { names.0.as_ref() }.ok_or(Error::NoneMapped)?;
+    // Safety: The domain list, when present, is valid until freed.
+    let trust = unsafe { domains.0.as_ref() }
+        .filter(|list| !list.Domains.is_null())
+        .and_then(|list| {
+            let index = usize::try_from(translated.DomainIndex).ok()?;
+            // Safety: `Domains` points to `Entries` trust information items.
+            unsafe { slice::from_raw_parts(list.Domains, list.Entries as usize) }.get(index)
+        });
+
+    Ok(SidLookupFull {
+        // Safety: LSA strings are valid until the buffers are freed.
+        name: unsafe { to_os_string(&translated.Name) },
+        // Safety: Same as above.
+        domain: trust.map_or_else(OsString::new, |trust| unsafe { to_os_string(&trust.Name) }),
+        domain_sid: trust
+            .filter(|trust| !trust.Sid.is_null())
+            // Safety: A non-null domain SID from LSA is a valid SID.
+            .map(|trust| SecurityIdentifier::from(unsafe { Sid::from_raw(trust.Sid) })),
+        sid_type: SidType::try_from(translated.Use).ok(),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::well_known;
+
+    #[test]
+    fn lookup_builtin_administrators() {
+        let sid = well_known::BUILTIN_ADMINISTRATORS.as_sid();
+        let full = lookup_local(sid).unwrap();
+        let local = sid.lookup_local_sid().unwrap().unwrap();
+        assert_eq!(full.name, local.domain_name.name);
+        assert_eq!(full.domain, local.domain_name.domain);
+        assert_eq!(full.sid_type, local.sid_type().ok());
+        assert_eq!(full.domain_sid.unwrap(), "S-1-5-32");
+    }
+}
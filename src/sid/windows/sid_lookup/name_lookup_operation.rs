@@ -0,0 +1,97 @@
+use crate::SecurityIdentifier;
+
+use super::Error;
+use core::num::NonZeroU32;
+use core::ptr::{null, null_mut};
+use smallvec::SmallVec;
+use widestring::U16CString;
+use windows_sys::Win32::Foundation::ERROR_INSUFFICIENT_BUFFER;
+use windows_sys::Win32::{Foundation::GetLastError, Security::LookupAccountNameW};
+
+pub struct NameLookupOperation<'a> {
+    pub name: &'a U16CString,
+    pub machine_name: Option<&'a U16CString>,
+    pub sid_len: u32,
+    pub domain_len: u32,
+    pub sid_type_raw: i32,
+}
+
+impl<'a> NameLookupOperation<'a> {
+    /// Probes the buffer sizes needed to look up `name`.
+    ///
+    /// `None` means the probe call succeeded, so no buffer sizes were reported;
+    /// any other failure than [`ERROR_INSUFFICIENT_BUFFER`] is returned as `Some(Err(_))`.
+    pub fn new(
+        name: &'a U16CString,
+        machine_name: Option<&'a U16CString>,
+    ) -> Option<Result<Self, Error>> {
+        let mut sid_len = 0u32;
+        let mut domain_len = 0u32;
+        let mut sid_type_raw = 0i32;
+
+        // Safety: All parameters of `LookupAccountNameW` are valid.
+        let result = unsafe {
+            LookupAccountNameW(
+                machine_name.map_or(null(), |s| s.as_ptr()),
+                name.as_ptr(),
+                null_mut(),
+                &raw mut sid_len,
+                null_mut(),
+                &raw mut domain_len,
+                &raw mut sid_type_raw,
+            )
+        };
+        if result != 0 {
+            return None;
+        }
+        // Safety: `GetLastError` is always safe to call.
+        match NonZeroU32::new(unsafe { GetLastError() }).map(Error::from) {
+            None => None,
+            Some(Error::Other(ERROR_INSUFFICIENT_BUFFER)) => Some(Ok(Self {
+                name,
+                machine_name,
+                sid_len,
+                domain_len,
+                sid_type_raw,
+            })),
+            Some(err) => Some(Err(err)),
+        }
+    }
+
+    pub(crate) fn process(mut self) -> Result<SecurityIdentifier, Error> {
+        let mut sid_buffer = SmallVec::<[u8; 128]>::with_capacity(self.sid_len as usize);
+        let mut domain_buffer = SmallVec::<[u16; 256]>::with_capacity(self.domain_len as usize);
+        let machine_name_ptr = self.machine_name.map_or(null(), |s| s.as_ptr());
+        // Safety: All parameters of `LookupAccountNameW` are valid.
+        let result = unsafe {
+            LookupAccountNameW(
+                machine_name_ptr,
+                self.name.as_ptr(),
+                sid_buffer.as_mut_ptr().cast(),
+                &raw mut self.sid_len,
+                domain_buffer.as_mut_ptr(),
+                &raw mut self.domain_len,
+                &raw mut self.sid_type_raw,
+            )
+        };
+        let result = (result == 0).then(|| {
+            // Safety: `GetLastError` is always safe to call.
+            let last_error = unsafe { GetLastError() };
+            Error::from(
+                // Safety: `last_error` is non-zero because `GetLastError` never returns 0 after an execution error.
+                unsafe { NonZeroU32::new_unchecked(last_error) },
+            )
+        });
+        match result {
+            Some(Error::Other(ERROR_INSUFFICIENT_BUFFER)) => self.process(),
+            Some(err) => Err(err),
+            None => {
+                // Safety: The buffer was allocated with the correct capacity and the call to `LookupAccountNameW` fill it.
+                unsafe {
+                    sid_buffer.set_len(self.sid_len as usize);
+                }
+                SecurityIdentifier::from_bytes(sid_buffer.as_slice()).map_err(|_| Error::InvalidSid)
+            }
+        }
+    }
+}
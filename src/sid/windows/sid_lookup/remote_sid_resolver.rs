@@ -0,0 +1,108 @@
+//! Reusable resolver performing many lookups against one machine.
+
+use std::ffi::OsStr;
+
+use widestring::U16CString;
+
+use super::name_lookup_operation::NameLookupOperation;
+use super::{Error, SidLookup, SidLookupOperation};
+use crate::{SecurityIdentifier, Sid};
+
+/// Performs SID and account name lookups against a single machine.
+///
+/// The machine name is converted to a wide string once at construction,
+/// instead of on every call like [`Sid::lookup_remote_sid`].
+///
+/// # Examples
+/// ```no_run
+/// # #[cfg(windows)]
+/// # {
+/// use win_security_identifier::{well_known, sid_lookup::RemoteSidResolver};
+/// let resolver = RemoteSidResolver::new("SERVER01").unwrap();
+/// for sid in [well_known::LOCAL_SYSTEM.as_sid(), well_known::BUILTIN_USERS.as_sid()] {
+///     if let Some(Ok(res)) = resolver.lookup(sid) {
+///         println!("{sid} => {}", res.domain_name);
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSidResolver {
+    machine_name: U16CString,
+}
+
+impl RemoteSidResolver {
+    /// Creates a resolver for `machine_name`.
+    ///
+    /// Returns `None` if the name contains an interior NUL.
+    #[inline]
+    #[must_use]
+    pub fn new<S: AsRef<OsStr>>(machine_name: S) -> Option<Self> {
+        U16CString::from_os_str(machine_name.as_ref())
+            .ok()
+            .map(|machine_name| Self { machine_name })
+    }
+
+    /// Performs a lookup of `sid` on this machine.
+    ///
+    /// Same semantics as [`Sid::lookup_remote_sid`].
+    #[inline]
+    #[must_use]
+    pub fn lookup(&self, sid: &Sid) -> Option<Result<SidLookup, Error>> {
+        SidLookupOperation::new(sid, Some(&self.machine_name))
+            .map(|op| op.and_then(SidLookupOperation::process))
+    }
+
+    /// Resolves an account name (e.g. `DOMAIN\Name`) to its SID on this machine.
+    ///
+    /// `None` means the name contains an interior NUL. Lookup failures are
+    /// reported as `Some(Err(_))`, e.g. [`Error::NoneMapped`] for an unknown
+    /// account.
+    #[inline]
+    #[must_use]
+    pub fn lookup_name(&self, name: &str) -> Option<Result<SecurityIdentifier, Error>> {
+        let name = U16CString::from_str(name).ok()?;
+        NameLookupOperation::new(&name, Some(&self.machine_name))
+            .map(|op| op.and_then(NameLookupOperation::process))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::well_known;
+
+    #[test]
+    fn resolve_well_known_on_local_machine() {
+        let resolver = RemoteSidResolver::new(".").unwrap();
+        for sid in [
+            well_known::LOCAL_SYSTEM.as_sid(),
+            well_known::BUILTIN_ADMINISTRATORS.as_sid(),
+            well_known::BUILTIN_USERS.as_sid(),
+        ] {
+            let res = resolver.lookup(sid).unwrap().unwrap();
+            let local = sid.lookup_local_sid().unwrap().unwrap();
+            assert_eq!(res.domain_name, local.domain_name);
+            assert_eq!(res.sid_type_raw, local.sid_type_raw);
+
+            let name = res.domain_name.to_string();
+            let from_name = resolver.lookup_name(&name).unwrap().unwrap();
+            assert_eq!(from_name, *sid, "Name {name} resolved to another SID");
+        }
+    }
+
+    #[test]
+    fn unknown_name_reports_none_mapped() {
+        let resolver = RemoteSidResolver::new(".").unwrap();
+        let result = resolver.lookup_name("no-such-account-7f3a9c").unwrap();
+        assert_eq!(result.err(), Some(Error::NoneMapped));
+    }
+
+    #[test]
+    fn interior_nul_is_rejected() {
+        assert!(RemoteSidResolver::new("a\0b").is_none());
+        let resolver = RemoteSidResolver::new(".").unwrap();
+        assert!(resolver.lookup_name("a\0b").is_none());
+    }
+}
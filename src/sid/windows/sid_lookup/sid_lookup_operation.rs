@@ -20,7 +20,11 @@ pub struct SidLookupOperation<'a> {
 }
 
 impl<'a> SidLookupOperation<'a> {
-    pub fn new(sid: &'a Sid, machine_name: Option<&'a U16CString>) -> Option<Self> {
+    /// Probes the buffer sizes needed to look up `sid`.
+    ///
+    /// `None` means the probe call succeeded, so no buffer sizes were reported;
+    /// any other failure than [`ERROR_INSUFFICIENT_BUFFER`] is returned as `Some(Err(_))`.
+    pub fn new(sid: &'a Sid, machine_name: Option<&'a U16CString>) -> Option<Result<Self, Error>> {
         let mut name_len = 0u32;
         let mut domain_len = 0u32;
         let mut sid_type_raw = 0i32;
@@ -41,18 +45,17 @@ impl<'a> SidLookupOperation<'a> {
             return None;
         }
         // Safety: `GetLastError` is always safe to call.
-        let err = NonZeroU32::new(unsafe { GetLastError() }).map(Error::from);
-        if err.is_none_or(|e| e != Error::Other(ERROR_INSUFFICIENT_BUFFER)) {
-            return None;
+        match NonZeroU32::new(unsafe { GetLastError() }).map(Error::from) {
+            None => None,
+            Some(Error::Other(ERROR_INSUFFICIENT_BUFFER)) => Some(Ok(Self {
+                sid,
+                machine_name,
+                name_len,
+                domain_len,
+                sid_type_raw,
+            })),
+            Some(err) => Some(Err(err)),
         }
-
-        Some(Self {
-            sid,
-            machine_name,
-            name_len,
-            domain_len,
-            sid_type_raw,
-        })
     }
 
     pub(crate) fn process(mut self) -> Result<SidLookup, Error> {
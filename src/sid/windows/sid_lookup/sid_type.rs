@@ -3,13 +3,19 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 /// like [LookupAccountSidW](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-lookupaccountsidw).
 /// # see also
 /// Microsoft docs for [SID_NAME_USE](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-sid_name_use).
+///
+/// Windows may introduce new `SID_NAME_USE` values, so this enum is `#[non_exhaustive]`;
+/// see [`SidLookup::sid_type_or_unknown`](super::SidLookup::sid_type_or_unknown) for a
+/// lossy, infallible conversion.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
 #[repr(i32)]
+#[non_exhaustive]
 pub enum SidType {
     /// A SID for a user account.
     User = 1,
 
     /// A SID for a group account.
+    Group = 2,
 
     /// A SID that identifies a domain.
     Domain = 3,
@@ -38,3 +44,57 @@ pub enum SidType {
     /// A logon session SID.
     LogonSession = 11,
 }
+
+impl SidType {
+    /// Name of the variant (e.g. `"User"`), as used for serialization.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(windows)]
+    /// # {
+    /// use win_security_identifier::sid_lookup::SidType;
+    /// assert_eq!(SidType::WellKnownGroup.as_str(), "WellKnownGroup");
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "User",
+            Self::Group => "Group",
+            Self::Domain => "Domain",
+            Self::Alias => "Alias",
+            Self::WellKnownGroup => "WellKnownGroup",
+            Self::DeletedAccount => "DeletedAccount",
+            Self::Invalid => "Invalid",
+            Self::Unknown => "Unknown",
+            Self::Computer => "Computer",
+            Self::Label => "Label",
+            Self::LogonSession => "LogonSession",
+        }
+    }
+}
+
+impl From<SidType> for &'static str {
+    #[inline]
+    fn from(value: SidType) -> Self {
+        value.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_matches_variant_name() {
+        for raw in 1..=11 {
+            let sid_type = SidType::try_from(raw).unwrap_or(SidType::Unknown);
+            assert_eq!(sid_type.as_str(), format!("{sid_type:?}"));
+            let name: &'static str = sid_type.into();
+            assert_eq!(name, sid_type.as_str());
+        }
+        assert_eq!(SidType::Group.as_str(), "Group");
+        assert_eq!(<&str>::from(SidType::User), "User");
+    }
+}
@@ -1,8 +1,25 @@
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
 /// Represents the identifier authority in a Security Identifier ([`crate::Sid`]).
+///
+/// Authorities are ordered by their 48-bit numeric value: since `value` is
+/// big-endian, this is the same as comparing the bytes lexicographically.
+///
+/// The authority is a 48-bit **big-endian** value, unlike the sub-authorities
+/// which are stored little-endian in the binary SID. Prefer
+/// [`from_u32_be`](Self::from_u32_be) and [`to_u32`](Self::to_u32) over
+/// building `value` by hand.
 pub struct SidIdentifierAuthority {
-    /// The raw bytes of the identifier authority.
+    /// The raw big-endian bytes of the identifier authority.
     pub value: [u8; 6],
 }
 
@@ -42,12 +59,154 @@ impl SidIdentifierAuthority {
     /// Used by Windows resource managers (e.g. for claims-based access control).
     pub const SECURITY_RESOURCE_MANAGER_AUTHORITY: Self = Self::new([0, 0, 0, 0, 0, 9]);
 
+    /// App Package Authority (S-1-15)
+    ///
+    /// Used by `AppContainer` package and capability SIDs.
+    pub const SECURITY_APP_PACKAGE_AUTHORITY: Self = Self::new([0, 0, 0, 0, 0, 15]);
+
+    /// Mandatory Label Authority (S-1-16)
+    ///
+    /// Used by integrity level SIDs (e.g. High Mandatory Level, `S-1-16-12288`).
+    pub const SECURITY_MANDATORY_LABEL_AUTHORITY: Self = Self::new([0, 0, 0, 0, 0, 16]);
+
+    /// Asserted Identity Authority (S-1-18)
+    ///
+    /// Used by the SIDs that tell how an identity was asserted (Windows 10 and later).
+    pub const ASSERTED_IDENTITY_AUTHORITY: Self = Self::new([0, 0, 0, 0, 0, 18]);
+
     /// Creates a new `SidIdentifierAuthority` from the raw bytes.
     #[inline]
     #[must_use]
     pub const fn new(value: [u8; 6]) -> Self {
         Self { value }
     }
+
+    /// Creates an authority from its numeric value (e.g. `5` for `S-1-5`).
+    ///
+    /// The value is stored big-endian in the low 4 bytes; the high 2 bytes are zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert_eq!(SidIdentifierAuthority::from_u32_be(5), SidIdentifierAuthority::NT_AUTHORITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_u32_be(value: u32) -> Self {
+        let [b0, b1, b2, b3] = value.to_be_bytes();
+        Self::new([0, 0, b0, b1, b2, b3])
+    }
+
+    /// Returns the 48-bit numeric value of this authority.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert_eq!(SidIdentifierAuthority::NT_AUTHORITY.as_u64(), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        let [b0, b1, b2, b3, b4, b5] = self.value;
+        u64::from_be_bytes([0, 0, b0, b1, b2, b3, b4, b5])
+    }
+
+    /// Creates an authority from its 48-bit numeric value.
+    ///
+    /// Returns `None` if `value` does not fit in 48 bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert_eq!(SidIdentifierAuthority::from_u64(5), Some(SidIdentifierAuthority::NT_AUTHORITY));
+    /// assert_eq!(SidIdentifierAuthority::from_u64(1 << 48), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_u64(value: u64) -> Option<Self> {
+        match value.to_be_bytes() {
+            [0, 0, b0, b1, b2, b3, b4, b5] => Some(Self::new([b0, b1, b2, b3, b4, b5])),
+            _ => None,
+        }
+    }
+
+    /// Returns the numeric value of this authority, if it fits in a `u32`.
+    ///
+    /// Returns `None` when one of the two high bytes is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert_eq!(SidIdentifierAuthority::NT_AUTHORITY.to_u32(), Some(5));
+    /// assert_eq!(SidIdentifierAuthority::new([1, 0, 0, 0, 0, 0]).to_u32(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_u32(self) -> Option<u32> {
+        match self.value {
+            [0, 0, b0, b1, b2, b3] => Some(u32::from_be_bytes([b0, b1, b2, b3])),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for the NT authority (`S-1-5`), see [`NT_AUTHORITY`](Self::NT_AUTHORITY).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert!(SidIdentifierAuthority::NT_AUTHORITY.is_nt());
+    /// assert!(!SidIdentifierAuthority::SECURITY_WORLD_AUTHORITY.is_nt());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_nt(self) -> bool {
+        matches!(self, Self::NT_AUTHORITY)
+    }
+
+    /// Returns `true` for the World authority (`S-1-1`), see
+    /// [`SECURITY_WORLD_AUTHORITY`](Self::SECURITY_WORLD_AUTHORITY).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert!(SidIdentifierAuthority::SECURITY_WORLD_AUTHORITY.is_world());
+    /// assert!(!SidIdentifierAuthority::NT_AUTHORITY.is_world());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_world(self) -> bool {
+        matches!(self, Self::SECURITY_WORLD_AUTHORITY)
+    }
+
+    /// Returns `true` for the Mandatory Label authority (`S-1-16`), see
+    /// [`SECURITY_MANDATORY_LABEL_AUTHORITY`](Self::SECURITY_MANDATORY_LABEL_AUTHORITY).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert!(SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY.is_mandatory_label());
+    /// assert!(!SidIdentifierAuthority::NT_AUTHORITY.is_mandatory_label());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_mandatory_label(self) -> bool {
+        matches!(self, Self::SECURITY_MANDATORY_LABEL_AUTHORITY)
+    }
+
+    /// Returns `true` for the App Package authority (`S-1-15`), see
+    /// [`SECURITY_APP_PACKAGE_AUTHORITY`](Self::SECURITY_APP_PACKAGE_AUTHORITY).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::SidIdentifierAuthority;
+    /// assert!(SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY.is_app_package());
+    /// assert!(!SidIdentifierAuthority::NT_AUTHORITY.is_app_package());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_app_package(self) -> bool {
+        matches!(self, Self::SECURITY_APP_PACKAGE_AUTHORITY)
+    }
 }
 
 impl Default for SidIdentifierAuthority {
@@ -72,6 +231,7 @@ impl From<SidIdentifierAuthority> for [u8; 6] {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
 pub mod test {
     use super::*;
     use proptest::prelude::*;
@@ -85,6 +245,139 @@ pub mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn test_u32_round_trip(value in any::<u32>()) {
+            prop_assert_eq!(SidIdentifierAuthority::from_u32_be(value).to_u32(), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut authorities = vec![
+            SidIdentifierAuthority::new([1, 0, 0, 0, 0, 0]),
+            SidIdentifierAuthority::NT_AUTHORITY,
+            SidIdentifierAuthority::new([0, 0, 0, 0, 1, 0]),
+            SidIdentifierAuthority::NULL_AUTHORITY,
+            SidIdentifierAuthority::SECURITY_RESOURCE_MANAGER_AUTHORITY,
+        ];
+        authorities.sort();
+        assert_eq!(
+            authorities,
+            [
+                SidIdentifierAuthority::NULL_AUTHORITY,
+                SidIdentifierAuthority::NT_AUTHORITY,
+                SidIdentifierAuthority::SECURITY_RESOURCE_MANAGER_AUTHORITY,
+                SidIdentifierAuthority::from_u32_be(0x100),
+                SidIdentifierAuthority::new([1, 0, 0, 0, 0, 0]),
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_ordering_matches_numeric(a in any::<u32>(), b in any::<u32>()) {
+            prop_assert_eq!(
+                SidIdentifierAuthority::from_u32_be(a).cmp(&SidIdentifierAuthority::from_u32_be(b)),
+                a.cmp(&b)
+            );
+        }
+    }
+
+    fn hash_of(value: SidIdentifierAuthority) -> u64 {
+        use core::hash::{Hash, Hasher};
+        let mut hasher = std::hash::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    prop_compose! {
+        fn arb_any_authority()(value in any::<[u8; 6]>()) -> SidIdentifierAuthority {
+            SidIdentifierAuthority::new(value)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_u64_round_trip(authority in arb_any_authority()) {
+            prop_assert_eq!(SidIdentifierAuthority::from_u64(authority.as_u64()), Some(authority));
+        }
+
+        #[test]
+        fn test_u64_consistent_with_eq_and_hash(a in arb_any_authority(), b in arb_any_authority()) {
+            prop_assert_eq!(a == b, a.as_u64() == b.as_u64());
+            prop_assert_eq!(a.cmp(&b), a.as_u64().cmp(&b.as_u64()));
+            if a == b {
+                prop_assert_eq!(hash_of(a), hash_of(b));
+            }
+            let copy = SidIdentifierAuthority::from_u64(a.as_u64()).unwrap();
+            prop_assert_eq!(hash_of(copy), hash_of(a));
+        }
+    }
+
+    #[test]
+    fn test_classification() {
+        type Predicate = fn(SidIdentifierAuthority) -> bool;
+        let cases: [(SidIdentifierAuthority, Predicate); 4] = [
+            (
+                SidIdentifierAuthority::NT_AUTHORITY,
+                SidIdentifierAuthority::is_nt,
+            ),
+            (
+                SidIdentifierAuthority::SECURITY_WORLD_AUTHORITY,
+                SidIdentifierAuthority::is_world,
+            ),
+            (
+                SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY,
+                SidIdentifierAuthority::is_mandatory_label,
+            ),
+            (
+                SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+                SidIdentifierAuthority::is_app_package,
+            ),
+        ];
+        for (index, (_, predicate)) in cases.iter().enumerate() {
+            for (other_index, (authority, _)) in cases.iter().enumerate() {
+                assert_eq!(predicate(*authority), index == other_index, "{authority:?}");
+            }
+            assert!(!predicate(SidIdentifierAuthority::NULL_AUTHORITY));
+            assert!(!predicate(SidIdentifierAuthority::new([1, 0, 0, 0, 0, 5])));
+        }
+    }
+
+    mod endianness {
+        use super::*;
+        use crate::well_known;
+
+        #[test]
+        fn authority_is_big_endian_and_rids_little_endian() {
+            let authority = SidIdentifierAuthority::from_u32_be(5);
+            assert_eq!(authority, SidIdentifierAuthority::NT_AUTHORITY);
+            assert_eq!(authority.value, [0, 0, 0, 0, 0, 5]);
+
+            let bytes = well_known::BUILTIN_ADMINISTRATORS.as_sid().as_binary();
+            assert_eq!(
+                bytes,
+                [
+                    1, 2, // revision, sub-authority count
+                    0, 0, 0, 0, 0, 5, // NT authority, big-endian
+                    0x20, 0, 0, 0, // 32, little-endian
+                    0x20, 0x02, 0, 0, // 544, little-endian
+                ]
+            );
+        }
+
+        #[test]
+        fn to_u32_rejects_high_bytes() {
+            let wide = SidIdentifierAuthority::new([0, 1, 0, 0, 0, 0]);
+            assert_eq!(wide.to_u32(), None);
+            assert_eq!(
+                SidIdentifierAuthority::from_u32_be(0x0102_0304).value,
+                [0, 0, 1, 2, 3, 4]
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn test_convertion_identity(value in super::test::arb_identifier_authority()) {
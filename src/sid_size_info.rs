@@ -37,7 +37,6 @@ impl SidSizeInfo {
     /// of a SID structure (head + sub-authorities).
     ///
     /// Returns `None` if the size is invalid.
-    #[allow(dead_code, reason = "useful method even if not used at this point")]
     pub const fn from_full_size(size: usize) -> Option<Self> {
         const MIN_SIZE: usize = SidSizeInfo::MIN.get_layout().size();
         const MAX_SIZE: usize = SidSizeInfo::MAX.get_layout().size();
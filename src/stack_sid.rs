@@ -58,6 +58,29 @@ impl StackSid {
         }
     }
 
+    /// Copies `sid` into a `StackSid`, checking at runtime that it fits.
+    ///
+    /// Unlike `From<&Sid>`, which only asserts the size in debug builds, this
+    /// returns an error when `sub_authority_count` exceeds the 15 sub-authorities
+    /// a `StackSid` can hold, e.g. for a malformed `Sid` obtained with `Sid::from_raw`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidSidFormat`] if `sid` has more than 15 sub-authorities.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{well_known, StackSid};
+    /// let sid = StackSid::try_from_sid(well_known::LOCAL_SYSTEM.as_sid()).unwrap();
+    /// assert_eq!(sid, well_known::LOCAL_SYSTEM);
+    /// ```
+    #[inline]
+    pub fn try_from_sid(sid: &Sid) -> Result<Self, InvalidSidFormat> {
+        if sid.sub_authority_count > MAX_SUBAUTHORITY_COUNT {
+            return Err(InvalidSidFormat);
+        }
+        Ok(Self::from(sid))
+    }
+
     /// Creates a new `StackSid` from parts **without validation**.
     ///
     /// # Safety
@@ -112,6 +135,10 @@ impl StackSid {
     #[inline]
     #[must_use]
     pub const fn as_sid(&self) -> &Sid {
+        debug_assert!(
+            self.sub_authority_count <= MAX_SUBAUTHORITY_COUNT,
+            "sub_authority_count exceeds the StackSid capacity"
+        );
         let raw: *const () = ptr::from_ref(self).cast();
         // SAFETY: Construct a fat pointer to `Sid` with metadata `N` that
         // matches `sub_authority_count`. The header layout is compatible
@@ -125,6 +152,10 @@ impl StackSid {
     /// with a trailing slice of sub-authorities.
     #[inline]
     pub const fn as_sid_mut(&mut self) -> &mut Sid {
+        debug_assert!(
+            self.sub_authority_count <= MAX_SUBAUTHORITY_COUNT,
+            "sub_authority_count exceeds the StackSid capacity"
+        );
         let raw: *mut () = ptr::from_mut(self).cast();
         // SAFETY: same justification as `as_sid`, but for a mutable reference.
         unsafe { &mut *from_raw_parts_mut(raw, self.sub_authority_count as usize) }
@@ -137,10 +168,16 @@ impl StackSid {
             pub const fn get_sub_authorities(&self) -> &[u32];
             #[must_use]
             #[inline]
+            pub const fn authority(&self) -> SidIdentifierAuthority;
+            #[must_use]
+            #[inline]
             pub const fn as_binary(&self) -> &[u8];
         }
 
         to self.as_sid_mut() {
+            #[must_use]
+            #[inline]
+            pub const fn sub_authorities_mut(&mut self) -> &mut [u32];
             /// Returns a `&mut [u8]` view over the **currently valid** minimal binary representation.
             ///
             /// This can be used for low-level, in-place updates when you know exactly what you are doing.
@@ -155,6 +192,32 @@ impl StackSid {
         }
     }
 
+    /// Copies the binary representation of this SID into a caller-owned fixed buffer.
+    ///
+    /// Returns the buffer and the number of valid bytes at its start (the same
+    /// bytes as [`as_binary`](Self::as_binary)); the remaining bytes are zeroed.
+    /// `68` bytes fit the largest possible SID (15 sub-authorities), which makes
+    /// the buffer easy to hand to C APIs without borrowing from `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::StackSid;
+    /// let sid: StackSid = "S-1-5-32-544".parse().unwrap();
+    /// let (buffer, len) = sid.to_fixed_bytes();
+    /// assert_eq!(len, 16);
+    /// assert_eq!(buffer.get(..len), Some(sid.as_binary()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_fixed_bytes(&self) -> ([u8; 68], usize) {
+        let mut buffer = [0u8; 68];
+        let binary = self.as_binary();
+        if let Some(prefix) = buffer.get_mut(..binary.len()) {
+            prefix.copy_from_slice(binary);
+        }
+        (buffer, binary.len())
+    }
+
     /// Creates a [`StackSid`] from its binary representation.
     ///
     /// `bytes` must contain a serialized Windows SID in the standard layout
@@ -197,6 +260,26 @@ impl StackSid {
     }
 }
 
+/// Parses a binary SID without allocating.
+///
+/// This is the recommended way to decode SIDs in `no_std` builds without the
+/// `alloc` feature, where [`SecurityIdentifier::from_bytes`](crate::SecurityIdentifier)
+/// is unavailable. It is equivalent to [`StackSid::from_bytes`].
+///
+/// # Errors
+/// Returns [`InvalidSidFormat`] if `bytes` is not a valid binary SID.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::{parse_sid_bytes, well_known};
+/// let bytes = well_known::BUILTIN_USERS.as_sid().as_binary();
+/// assert_eq!(parse_sid_bytes(bytes).unwrap(), well_known::BUILTIN_USERS);
+/// ```
+#[inline]
+pub const fn parse_sid_bytes(bytes: &[u8]) -> Result<StackSid, InvalidSidFormat> {
+    StackSid::from_bytes(bytes)
+}
+
 impl Borrow<Sid> for StackSid {
     #[inline]
     fn borrow(&self) -> &Sid {
@@ -265,6 +348,39 @@ impl<'a> TryFrom<&'a [u8]> for StackSid {
     }
 }
 
+impl<'a> TryFrom<&'a str> for StackSid {
+    type Error = InvalidSidFormat;
+
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<'a> TryFrom<(SidIdentifierAuthority, &'a [u32])> for StackSid {
+    type Error = InvalidSidFormat;
+
+    /// Builds a `StackSid` from an identifier authority and its sub-authorities.
+    ///
+    /// # Errors
+    /// Returns [`InvalidSidFormat`] when the sub-authority count is not in `1..=15`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use win_security_identifier::{StackSid, SidIdentifierAuthority};
+    /// let subs = [32u32, 544u32];
+    /// let sid = StackSid::try_from((SidIdentifierAuthority::NT_AUTHORITY, &subs[..])).unwrap();
+    /// assert_eq!(sid.to_string(), "S-1-5-32-544");
+    /// assert!(StackSid::try_from((SidIdentifierAuthority::NT_AUTHORITY, &[][..])).is_err());
+    /// ```
+    #[inline]
+    fn try_from(
+        (identifier_authority, sub_authority): (SidIdentifierAuthority, &'a [u32]),
+    ) -> Result<Self, Self::Error> {
+        Self::try_new(identifier_authority, sub_authority).ok_or(InvalidSidFormat)
+    }
+}
+
 impl From<&Sid> for StackSid {
     #[inline]
     fn from(value: &Sid) -> Self {
@@ -310,7 +426,7 @@ impl FromStr for StackSid {
 impl Display for StackSid {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_sid())
+        Display::fmt(self.as_sid(), f)
     }
 }
 
@@ -346,6 +462,35 @@ impl PartialEq<StackSid> for Sid {
     }
 }
 
+impl PartialEq<str> for StackSid {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_sid() == other
+    }
+}
+
+impl PartialEq<&str> for StackSid {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_sid() == *other
+    }
+}
+
+impl PartialEq<[u8]> for StackSid {
+    /// Compares the binary form of this SID (see `as_binary`) with `other`.
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_binary() == other
+    }
+}
+
+impl PartialEq<&[u8]> for StackSid {
+    #[inline]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.as_binary() == *other
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -396,6 +541,81 @@ mod tests {
             prop_assert_eq!(metadata(sid_ref), sid.sub_authority_count as usize);
         }
     }
+    proptest! {
+        #[test]
+        fn test_try_from_components(identifier_authority in arb_identifier_authority(), subs in proptest::collection::vec(any::<u32>(), 0..=16)) {
+            let from_tuple = StackSid::try_from((identifier_authority, subs.as_slice()));
+            let from_new = StackSid::try_new(identifier_authority, &subs);
+            prop_assert_eq!(from_tuple.is_ok(), (1..=15).contains(&subs.len()));
+            prop_assert_eq!(from_tuple.ok(), from_new);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "sub_authority_count")]
+    fn test_corrupted_count_is_caught() {
+        let mut sid = StackSid::from(well_known::BUILTIN_ADMINISTRATORS.as_sid());
+        sid.sub_authority_count = MAX_SUBAUTHORITY_COUNT + 1;
+        let _ = sid.as_binary();
+    }
+
+    proptest! {
+        #[test]
+        fn test_to_fixed_bytes(sid in arb_stack_sid()) {
+            let (buffer, len) = sid.to_fixed_bytes();
+            prop_assert_eq!(buffer.len(), size_of::<StackSid>());
+            let (prefix, tail) = buffer.split_at(len);
+            prop_assert_eq!(prefix, sid.as_binary());
+            prop_assert!(tail.iter().all(|&byte| byte == 0));
+        }
+
+        #[test]
+        fn test_from_bytes_round_trip(sid in arb_stack_sid(), offset in 0usize..4) {
+            // Shift the blob so unaligned buffers are covered as well.
+            let mut buffer = vec![0xAAu8; offset];
+            buffer.extend_from_slice(sid.as_binary());
+            let (_, bytes) = buffer.split_at(offset);
+            prop_assert_eq!(StackSid::from_bytes(bytes), Ok(sid));
+        }
+    }
+
+    #[test]
+    fn test_eq_bytes() {
+        let sid = StackSid::from(well_known::BUILTIN_USERS.as_sid());
+        let bytes = well_known::BUILTIN_USERS.as_sid().as_binary();
+        assert!(sid == *bytes);
+        assert!(sid == bytes);
+        assert!(sid != well_known::BUILTIN_ADMINISTRATORS.as_sid().as_binary());
+        assert!(sid != b"S-1-5-32-545".as_slice());
+    }
+
+    #[test]
+    fn test_try_from_sid_rejects_oversize() {
+        assert_eq!(
+            StackSid::try_from_sid(well_known::BUILTIN_USERS.as_sid()).unwrap(),
+            well_known::BUILTIN_USERS
+        );
+
+        let oversized = MAX_SUBAUTHORITY_COUNT + 1;
+        let mut buffer = [0u32; 2 + MAX_SUBAUTHORITY_COUNT as usize + 1];
+        if let Some(head) = buffer.first_mut() {
+            *head = u32::from_ne_bytes([Sid::REVISION, oversized, 0, 0]);
+        }
+        // SAFETY: The buffer holds the 8-byte header plus `oversized` sub-authorities.
+        let sid: &Sid =
+            unsafe { &*from_raw_parts(buffer.as_ptr().cast::<()>(), usize::from(oversized)) };
+        assert_eq!(sid.get_sub_authorities().len(), usize::from(oversized));
+        assert_eq!(StackSid::try_from_sid(sid), Err(InvalidSidFormat));
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let sid = StackSid::try_from("S-1-5-32-544").unwrap();
+        assert_eq!(sid, "S-1-5-32-544".parse::<StackSid>().unwrap());
+        assert_eq!(StackSid::try_from("S-1-5-"), Err(InvalidSidFormat));
+    }
+
     #[test]
     fn test_debug() {
         let sample_sid = well_known::NULL;
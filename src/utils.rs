@@ -12,42 +12,61 @@ pub const fn sub_authority_size_guard(size: usize) -> bool {
     MIN_SUBAUTHORITY_COUNT as usize <= size && size <= MAX_SUBAUTHORITY_COUNT as usize
 }
 
-/// Validates a raw SID blob like `IsValidSid` would, without assuming alignment.
-pub const fn validate_sid_bytes_unaligned(buf: &[u8]) -> Result<(), InvalidSidFormat> {
-    const REVISION_OFFSET: usize = offset_of!(Sid, revision);
+/// Checks only that the length of a raw SID blob matches its declared sub-authority count.
+///
+/// This is the subset of [`validate_sid_bytes_unaligned`] that ignores the revision.
+pub const fn sid_bytes_len_matches_count(buf: &[u8]) -> bool {
     const COUNT_OFFSET: usize = offset_of!(Sid, sub_authority_count);
     const MIN_SIZE: usize = SidSizeInfo::MIN.get_layout().size();
     if buf.len() < MIN_SIZE {
-        return Err(InvalidSidFormat);
+        return false;
     }
 
     #[expect(
         clippy::indexing_slicing,
-        reason = "We know the revision_offset is in the bound (was checked by minimum size)"
+        reason = "We know the count_offset is in the bound (was checked by minimum size)"
     )]
-    if buf[REVISION_OFFSET] != Sid::REVISION {
+    let count = buf[COUNT_OFFSET];
+
+    match SidSizeInfo::from_count(count) {
+        Some(info) => info.get_layout().size() == buf.len(),
+        None => false,
+    }
+}
+
+/// Validates a raw SID blob like `IsValidSid` would, without assuming alignment.
+pub const fn validate_sid_bytes_unaligned(buf: &[u8]) -> Result<(), InvalidSidFormat> {
+    const REVISION_OFFSET: usize = offset_of!(Sid, revision);
+    if !sid_bytes_len_matches_count(buf) {
         return Err(InvalidSidFormat);
     }
+
     #[expect(
         clippy::indexing_slicing,
-        reason = "We know the count_offset is in the bound (was checked by minimum size)"
+        reason = "We know the revision_offset is in the bound (was checked by minimum size)"
     )]
-    let count = buf[COUNT_OFFSET];
-
-    if !sub_authority_size_guard(count as usize) {
+    if buf[REVISION_OFFSET] != Sid::REVISION {
         return Err(InvalidSidFormat);
     }
 
-    // SAFETY: size already validated
-    let size = unsafe { SidSizeInfo::from_count(count).unwrap_unchecked() }
-        .get_layout()
-        .size();
+    Ok(())
+}
 
-    if buf.len() != size {
+/// Validates a raw SID blob like [`validate_sid_bytes_unaligned`], reading the
+/// fixed [`SidHead`](crate::sid::SidHead) through `zerocopy` instead of offsets.
+#[cfg(all(feature = "zerocopy", feature = "alloc"))]
+pub fn validate_sid_bytes_zerocopy(buf: &[u8]) -> Result<(), InvalidSidFormat> {
+    use zerocopy::{FromBytes, little_endian::U32};
+
+    let (head, tail) = crate::sid::SidHead::ref_from_prefix(buf).map_err(|_| InvalidSidFormat)?;
+    if head.revision != Sid::REVISION
+        || !sub_authority_size_guard(usize::from(head.sub_authority_count))
+    {
         return Err(InvalidSidFormat);
     }
-
-    Ok(())
+    <[U32]>::ref_from_bytes_with_elems(tail, usize::from(head.sub_authority_count))
+        .map(|_| ())
+        .map_err(|_| InvalidSidFormat)
 }
 
 #[expect(
@@ -62,12 +81,18 @@ pub fn debug_print<T: Borrow<Sid> + ?Sized>(
 ) -> fmt::Result {
     let sid = sid.borrow();
     if f.alternate() {
-        f.debug_struct(struct_name)
+        let mut debug = f.debug_struct(struct_name);
+        debug
             .field("revision", &sid.revision)
             .field("sub_authority_count", &sid.sub_authority_count)
             .field("identifier_authority", &sid.identifier_authority)
-            .field("sub_authority", &sid.get_sub_authorities())
-            .finish()
+            .field("sub_authority", &sid.get_sub_authorities());
+        // Best effort: the account is only shown when the local lookup succeeds.
+        #[cfg(all(windows, feature = "std"))]
+        if let Some(Ok(lookup)) = sid.lookup_local_sid() {
+            debug.field("account", &format_args!("{}", lookup.domain_name));
+        }
+        debug.finish()
     } else {
         write!(f, "{struct_name}({sid})")
     }
@@ -199,6 +224,23 @@ mod test {
 
             prop_assert_eq!(validate_sid_bytes_unaligned(&buf), Err(InvalidSidFormat));
         }
+        #[test]
+        fn proptest_len_check_is_subset_of_validation(buf in proptest::collection::vec(any::<u8>(), 0..80)) {
+            let plausible = sid_bytes_len_matches_count(&buf);
+            let valid = validate_sid_bytes_unaligned(&buf).is_ok();
+            prop_assert!(!valid || plausible);
+            prop_assert_eq!(valid, plausible && buf[REVISION_OFFSET] == Sid::REVISION);
+        }
+
+        #[test]
+        fn proptest_len_check_ignores_revision(revision in any::<u8>(), count in MIN_SUBAUTHORITY_COUNT..=MAX_SUBAUTHORITY_COUNT) {
+            let mut buf = make_sid_bytes(count);
+            buf[REVISION_OFFSET] = revision;
+            prop_assert!(sid_bytes_len_matches_count(&buf));
+            buf.push(0);
+            prop_assert!(!sid_bytes_len_matches_count(&buf));
+        }
+
         #[test]
         fn proptest_wrong_revision_is_rejected(revision in prop_oneof![Just(0u8), 2u8..], count in MIN_SUBAUTHORITY_COUNT..=MAX_SUBAUTHORITY_COUNT){
             let mut buf =make_sid_bytes(count);
@@ -206,4 +248,25 @@ mod test {
             prop_assert_eq!(validate_sid_bytes_unaligned(&buf), Err(InvalidSidFormat));
         }
     }
+
+    #[cfg(all(feature = "zerocopy", feature = "alloc"))]
+    proptest! {
+        #[test]
+        fn proptest_zerocopy_matches_unaligned(buf in proptest::collection::vec(any::<u8>(), 0..80)) {
+            prop_assert_eq!(validate_sid_bytes_zerocopy(&buf), validate_sid_bytes_unaligned(&buf));
+        }
+
+        #[test]
+        fn proptest_zerocopy_matches_unaligned_near_valid(
+            revision in prop_oneof![Just(Sid::REVISION), any::<u8>()],
+            count in 0..=MAX_SUBAUTHORITY_COUNT + 1,
+            len_delta in -4isize..=4,
+        ) {
+            let mut buf = make_sid_bytes(count.clamp(MIN_SUBAUTHORITY_COUNT, MAX_SUBAUTHORITY_COUNT));
+            buf[REVISION_OFFSET] = revision;
+            buf[COUNT_OFFSET] = count;
+            buf.resize(buf.len().saturating_add_signed(len_delta), 0);
+            prop_assert_eq!(validate_sid_bytes_zerocopy(&buf), validate_sid_bytes_unaligned(&buf));
+        }
+    }
 }
@@ -5,8 +5,16 @@
 //! This module exposes all well-known SIDs as constants. Users do not need to
 //! know the number of sub-authorities (`ConstSid<N>`), each SID is directly
 //! accessible as a constant reference.
+//!
+//! Each constant also has a `*_SID` static of type `&'static Sid`, usable
+//! directly in `static` or `const` lookup tables.
 
-use crate::{ConstSid, SidIdentifierAuthority};
+use crate::rid::{ALIAS_ADMINS, ALIAS_GUESTS, ALIAS_POWER_USERS, ALIAS_USERS, BUILTIN_DOMAIN};
+use crate::{ConstSid, Sid, SidIdentifierAuthority};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use ::alloc::vec::Vec;
+#[cfg(feature = "service-sid")]
+use sha1::{Digest, Sha1};
 
 // ---- Basic Authorities ----
 
@@ -16,6 +24,9 @@ pub const NULL: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NULL_AUTHORI
 /// World SID (S-1-1-0)
 pub const WORLD: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::SECURITY_WORLD_AUTHORITY, [0]);
 
+/// Everyone group (S-1-1-0), alias of [`WORLD`]
+pub const EVERYONE: ConstSid<1> = WORLD;
+
 /// Local SID (S-1-2-0)
 pub const LOCAL: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::SECURITY_LOCAL_AUTHORITY, [0]);
 
@@ -32,26 +43,609 @@ pub const CREATOR_GROUP: ConstSid<1> =
 /// Local System (S-1-5-18)
 pub const LOCAL_SYSTEM: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [18]);
 
+/// SYSTEM account (S-1-5-18), alias of [`LOCAL_SYSTEM`]
+pub const SYSTEM: ConstSid<1> = LOCAL_SYSTEM;
+
 /// Local Service (S-1-5-19)
 pub const LOCAL_SERVICE: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [19]);
 
 /// Network Service (S-1-5-20)
 pub const NETWORK_SERVICE: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [20]);
 
+/// Network logon group (S-1-5-2)
+pub const NETWORK: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [2]);
+
+/// Batch logon group (S-1-5-3)
+pub const BATCH: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [3]);
+
+/// Interactive logon group (S-1-5-4)
+pub const INTERACTIVE: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [4]);
+
+/// Service logon group (S-1-5-6)
+pub const SERVICE: ConstSid<1> = ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [6]);
+
+/// Authenticated Users group (S-1-5-11)
+pub const AUTHENTICATED_USERS: ConstSid<1> =
+    ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [11]);
+
 // ---- BUILTIN Domain (S-1-5-32) ----
 
 /// BUILTIN\Administrators (S-1-5-32-544)
-pub const BUILTIN_ADMINISTRATORS: ConstSid<2> =
-    ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [32, 544]);
+pub const BUILTIN_ADMINISTRATORS: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::NT_AUTHORITY,
+    [BUILTIN_DOMAIN, ALIAS_ADMINS],
+);
 
 /// BUILTIN\Users (S-1-5-32-545)
-pub const BUILTIN_USERS: ConstSid<2> =
-    ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [32, 545]);
+pub const BUILTIN_USERS: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::NT_AUTHORITY,
+    [BUILTIN_DOMAIN, ALIAS_USERS],
+);
 
 /// BUILTIN\Guests (S-1-5-32-546)
-pub const BUILTIN_GUESTS: ConstSid<2> =
-    ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [32, 546]);
+pub const BUILTIN_GUESTS: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::NT_AUTHORITY,
+    [BUILTIN_DOMAIN, ALIAS_GUESTS],
+);
 
 /// BUILTIN\Power Users (S-1-5-32-547)
-pub const BUILTIN_POWER_USERS: ConstSid<2> =
-    ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, [32, 547]);
+pub const BUILTIN_POWER_USERS: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::NT_AUTHORITY,
+    [BUILTIN_DOMAIN, ALIAS_POWER_USERS],
+);
+
+// ---- NT SERVICE (S-1-5-80) ----
+
+/// NT SERVICE\TrustedInstaller (S-1-5-80-956008885-3418522649-1831038044-1853292631-2271478464)
+pub const TRUSTED_INSTALLER: ConstSid<6> = ConstSid::new(
+    SidIdentifierAuthority::NT_AUTHORITY,
+    [
+        80,
+        956_008_885,
+        3_418_522_649,
+        1_831_038_044,
+        1_853_292_631,
+        2_271_478_464,
+    ],
+);
+
+// ---- App capabilities (S-1-15-3) ----
+
+/// internetClient capability (S-1-15-3-1)
+pub const INTERNET_CLIENT: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 1],
+);
+
+/// internetClientServer capability (S-1-15-3-2)
+pub const INTERNET_CLIENT_SERVER: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 2],
+);
+
+/// privateNetworkClientServer capability (S-1-15-3-3)
+pub const PRIVATE_NETWORK_CLIENT_SERVER: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 3],
+);
+
+/// picturesLibrary capability (S-1-15-3-4)
+pub const PICTURES_LIBRARY: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 4],
+);
+
+/// videosLibrary capability (S-1-15-3-5)
+pub const VIDEOS_LIBRARY: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 5],
+);
+
+/// musicLibrary capability (S-1-15-3-6)
+pub const MUSIC_LIBRARY: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 6],
+);
+
+/// documentsLibrary capability (S-1-15-3-7)
+pub const DOCUMENTS_LIBRARY: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 7],
+);
+
+/// enterpriseAuthentication capability (S-1-15-3-8)
+pub const ENTERPRISE_AUTHENTICATION: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 8],
+);
+
+/// sharedUserCertificates capability (S-1-15-3-9)
+pub const SHARED_USER_CERTIFICATES: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 9],
+);
+
+/// removableStorage capability (S-1-15-3-10)
+pub const REMOVABLE_STORAGE: ConstSid<2> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_APP_PACKAGE_AUTHORITY,
+    [3, 10],
+);
+
+// ---- Mandatory Label Authority (S-1-16) ----
+
+/// Low Mandatory Level (S-1-16-4096)
+pub const LOW_INTEGRITY: ConstSid<1> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY,
+    [4096],
+);
+
+/// Medium Mandatory Level (S-1-16-8192)
+pub const MEDIUM_INTEGRITY: ConstSid<1> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY,
+    [8192],
+);
+
+/// Medium Plus Mandatory Level (S-1-16-8448)
+pub const MEDIUM_PLUS_INTEGRITY: ConstSid<1> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY,
+    [8448],
+);
+
+/// High Mandatory Level (S-1-16-12288)
+pub const HIGH_INTEGRITY: ConstSid<1> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY,
+    [12288],
+);
+
+/// System Mandatory Level (S-1-16-16384)
+pub const SYSTEM_INTEGRITY: ConstSid<1> = ConstSid::new(
+    SidIdentifierAuthority::SECURITY_MANDATORY_LABEL_AUTHORITY,
+    [16384],
+);
+
+// ---- Asserted Identity Authority (S-1-18) ----
+
+/// Authentication authority asserted identity (S-1-18-1)
+///
+/// Added to the token when the identity was asserted by an authentication
+/// authority from the client's credentials.
+pub const AUTHENTICATION_AUTHORITY_ASSERTED_IDENTITY: ConstSid<1> =
+    ConstSid::new(SidIdentifierAuthority::ASSERTED_IDENTITY_AUTHORITY, [1]);
+
+/// Service asserted identity (S-1-18-2)
+///
+/// Added to the token when the identity was asserted by a service.
+pub const SERVICE_ASSERTED_IDENTITY: ConstSid<1> =
+    ConstSid::new(SidIdentifierAuthority::ASSERTED_IDENTITY_AUTHORITY, [2]);
+
+// ---- `&'static Sid` views ----
+
+macro_rules! sid_statics {
+    ($($name:ident => $sid:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("[`", stringify!($sid), "`] as a `&'static Sid`.")]
+            pub static $name: &Sid = (&$sid).as_sid();
+        )*
+    };
+}
+
+sid_statics! {
+    NULL_SID => NULL,
+    WORLD_SID => WORLD,
+    EVERYONE_SID => EVERYONE,
+    LOCAL_SID => LOCAL,
+    CREATOR_OWNER_SID => CREATOR_OWNER,
+    CREATOR_GROUP_SID => CREATOR_GROUP,
+    LOCAL_SYSTEM_SID => LOCAL_SYSTEM,
+    SYSTEM_SID => SYSTEM,
+    LOCAL_SERVICE_SID => LOCAL_SERVICE,
+    NETWORK_SERVICE_SID => NETWORK_SERVICE,
+    NETWORK_SID => NETWORK,
+    BATCH_SID => BATCH,
+    INTERACTIVE_SID => INTERACTIVE,
+    SERVICE_SID => SERVICE,
+    AUTHENTICATED_USERS_SID => AUTHENTICATED_USERS,
+    BUILTIN_ADMINISTRATORS_SID => BUILTIN_ADMINISTRATORS,
+    BUILTIN_USERS_SID => BUILTIN_USERS,
+    BUILTIN_GUESTS_SID => BUILTIN_GUESTS,
+    BUILTIN_POWER_USERS_SID => BUILTIN_POWER_USERS,
+    TRUSTED_INSTALLER_SID => TRUSTED_INSTALLER,
+    INTERNET_CLIENT_SID => INTERNET_CLIENT,
+    INTERNET_CLIENT_SERVER_SID => INTERNET_CLIENT_SERVER,
+    PRIVATE_NETWORK_CLIENT_SERVER_SID => PRIVATE_NETWORK_CLIENT_SERVER,
+    PICTURES_LIBRARY_SID => PICTURES_LIBRARY,
+    VIDEOS_LIBRARY_SID => VIDEOS_LIBRARY,
+    MUSIC_LIBRARY_SID => MUSIC_LIBRARY,
+    DOCUMENTS_LIBRARY_SID => DOCUMENTS_LIBRARY,
+    ENTERPRISE_AUTHENTICATION_SID => ENTERPRISE_AUTHENTICATION,
+    SHARED_USER_CERTIFICATES_SID => SHARED_USER_CERTIFICATES,
+    REMOVABLE_STORAGE_SID => REMOVABLE_STORAGE,
+    LOW_INTEGRITY_SID => LOW_INTEGRITY,
+    MEDIUM_INTEGRITY_SID => MEDIUM_INTEGRITY,
+    MEDIUM_PLUS_INTEGRITY_SID => MEDIUM_PLUS_INTEGRITY,
+    HIGH_INTEGRITY_SID => HIGH_INTEGRITY,
+    SYSTEM_INTEGRITY_SID => SYSTEM_INTEGRITY,
+    AUTHENTICATION_AUTHORITY_ASSERTED_IDENTITY_SID => AUTHENTICATION_AUTHORITY_ASSERTED_IDENTITY,
+    SERVICE_ASSERTED_IDENTITY_SID => SERVICE_ASSERTED_IDENTITY,
+}
+
+/// Well-known SIDs whose account type is a well-known group (see [`Sid::is_well_known_group`]).
+pub(crate) static WELL_KNOWN_GROUPS: [&Sid; 7] = [
+    WORLD_SID,
+    LOCAL_SID,
+    NETWORK_SID,
+    BATCH_SID,
+    INTERACTIVE_SID,
+    SERVICE_SID,
+    AUTHENTICATED_USERS_SID,
+];
+
+/// English account names (`DOMAIN\Name`, or `Name` alone when the SID has no
+/// domain) of the SIDs in this module, as reported by an English Windows.
+static NAMES: [(&str, &Sid); 23] = [
+    ("NULL SID", NULL_SID),
+    ("Everyone", WORLD_SID),
+    ("LOCAL", LOCAL_SID),
+    ("CREATOR OWNER", CREATOR_OWNER_SID),
+    ("CREATOR GROUP", CREATOR_GROUP_SID),
+    ("NT AUTHORITY\\SYSTEM", LOCAL_SYSTEM_SID),
+    ("NT AUTHORITY\\LOCAL SERVICE", LOCAL_SERVICE_SID),
+    ("NT AUTHORITY\\NETWORK SERVICE", NETWORK_SERVICE_SID),
+    ("NT AUTHORITY\\NETWORK", NETWORK_SID),
+    ("NT AUTHORITY\\BATCH", BATCH_SID),
+    ("NT AUTHORITY\\INTERACTIVE", INTERACTIVE_SID),
+    ("NT AUTHORITY\\SERVICE", SERVICE_SID),
+    ("NT AUTHORITY\\Authenticated Users", AUTHENTICATED_USERS_SID),
+    ("BUILTIN\\Administrators", BUILTIN_ADMINISTRATORS_SID),
+    ("BUILTIN\\Users", BUILTIN_USERS_SID),
+    ("BUILTIN\\Guests", BUILTIN_GUESTS_SID),
+    ("BUILTIN\\Power Users", BUILTIN_POWER_USERS_SID),
+    ("NT SERVICE\\TrustedInstaller", TRUSTED_INSTALLER_SID),
+    ("Mandatory Label\\Low Mandatory Level", LOW_INTEGRITY_SID),
+    (
+        "Mandatory Label\\Medium Mandatory Level",
+        MEDIUM_INTEGRITY_SID,
+    ),
+    (
+        "Mandatory Label\\Medium Plus Mandatory Level",
+        MEDIUM_PLUS_INTEGRITY_SID,
+    ),
+    ("Mandatory Label\\High Mandatory Level", HIGH_INTEGRITY_SID),
+    (
+        "Mandatory Label\\System Mandatory Level",
+        SYSTEM_INTEGRITY_SID,
+    ),
+];
+
+/// Returns the well-known SID named `name`, without touching Windows.
+///
+/// `name` is matched case-insensitively against the English account name,
+/// either in full (`"BUILTIN\\Administrators"`) or without its domain
+/// (`"Administrators"`). Localized names are not recognized.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::well_known;
+/// assert_eq!(well_known::by_name("Everyone"), Some(well_known::WORLD_SID));
+/// assert_eq!(
+///     well_known::by_name(r"builtin\administrators"),
+///     Some(well_known::BUILTIN_ADMINISTRATORS_SID)
+/// );
+/// assert_eq!(well_known::by_name("SYSTEM"), Some(well_known::LOCAL_SYSTEM_SID));
+/// assert_eq!(well_known::by_name("Nobody"), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn by_name(name: &str) -> Option<&'static Sid> {
+    NAMES
+        .iter()
+        .find(|(known, _)| {
+            known.eq_ignore_ascii_case(name)
+                || known
+                    .split_once('\\')
+                    .is_some_and(|(_, account)| account.eq_ignore_ascii_case(name))
+        })
+        .map(|(_, sid)| *sid)
+}
+
+/// Returns the English account name of a well-known SID (e.g. `"BUILTIN\\Users"`).
+///
+/// This is the reverse of [`by_name`]; it returns `None` for SIDs not defined
+/// in this module.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::well_known;
+/// assert_eq!(well_known::well_known_name(well_known::BUILTIN_USERS.as_sid()), Some(r"BUILTIN\Users"));
+/// ```
+#[inline]
+#[must_use]
+pub fn well_known_name(sid: &Sid) -> Option<&'static str> {
+    NAMES
+        .iter()
+        .find(|(_, known)| *known == sid)
+        .map(|(name, _)| *name)
+}
+
+/// Derives the service SID of `NT SERVICE\<service_name>` (`S-1-5-80-...`).
+///
+/// The five sub-authorities following `80` are the SHA-1 digest of the
+/// uppercased UTF-16LE service name, read as little-endian `u32`s. Uppercasing
+/// maps each character to its single-character uppercase form, like Windows
+/// does; characters whose uppercase form expands (e.g. `ß`) are kept unchanged.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::well_known;
+/// let sid = well_known::service_sid("TrustedInstaller");
+/// assert_eq!(
+///     sid,
+///     "S-1-5-80-956008885-3418522649-1831038044-1853292631-2271478464"
+/// );
+/// assert!(sid.as_sid().is_service_sid());
+/// ```
+#[cfg(feature = "service-sid")]
+#[inline]
+#[must_use]
+pub fn service_sid(service_name: &str) -> ConstSid<6> {
+    const SECURITY_SERVICE_ID_BASE_RID: u32 = 80;
+    let mut hasher = Sha1::new();
+    let mut utf16 = [0u16; 2];
+    for c in service_name.chars() {
+        let mut upper = c.to_uppercase();
+        let upper = match (upper.next(), upper.next()) {
+            (Some(single), None) => single,
+            _ => c,
+        };
+        for unit in upper.encode_utf16(&mut utf16) {
+            hasher.update(unit.to_le_bytes());
+        }
+    }
+    let digest: [u8; 20] = hasher.finalize().into();
+    let mut sub_authority = [SECURITY_SERVICE_ID_BASE_RID; 6];
+    for (value, chunk) in sub_authority.iter_mut().skip(1).zip(digest.chunks_exact(4)) {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(chunk);
+        *value = u32::from_le_bytes(bytes);
+    }
+    ConstSid::new(SidIdentifierAuthority::NT_AUTHORITY, sub_authority)
+}
+
+/// Derives the SID of the virtual service account `NT SERVICE\<service_name>`
+/// as an owned [`SecurityIdentifier`](crate::SecurityIdentifier).
+///
+/// This is the public entry point for service SIDs; it works on every
+/// platform and never queries Windows. The algorithm is the one of
+/// [`service_sid`]: `S-1-5-80-` followed by the SHA-1 digest of the uppercased
+/// UTF-16LE service name, split into five little-endian `u32` sub-authorities.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::well_known;
+/// let sid = well_known::service_sid_from_name("MSSQLSERVER");
+/// assert_eq!(
+///     sid,
+///     "S-1-5-80-3880718306-3832830129-1677859214-2598158968-1052248003"
+/// );
+/// ```
+#[cfg(all(feature = "service-sid", feature = "alloc"))]
+#[inline]
+#[must_use]
+pub fn service_sid_from_name(service_name: &str) -> crate::SecurityIdentifier {
+    service_sid(service_name).into()
+}
+
+/// Builds the standard group SIDs of `domain`.
+///
+/// These are Domain Admins, Users, Guests, Computers and Controllers (RIDs
+/// [`DOMAIN_ADMINS`](crate::rid::DOMAIN_ADMINS) to
+/// [`DOMAIN_CONTROLLERS`](crate::rid::DOMAIN_CONTROLLERS)), in that order.
+/// Each SID is `domain` with the group RID appended; `domain` itself is not
+/// checked to be a domain SID. The result is empty when `domain` already has
+/// the maximum of 15 sub-authorities.
+///
+/// # Examples
+/// ```rust
+/// # use win_security_identifier::{well_known, StackSid};
+/// let domain: StackSid = "S-1-5-21-1-2-3".parse().unwrap();
+/// let groups = well_known::expand_domain_groups(domain.as_sid());
+/// assert_eq!(groups[0], "S-1-5-21-1-2-3-512");
+/// assert_eq!(groups.len(), 5);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn expand_domain_groups(domain: &Sid) -> Vec<crate::SecurityIdentifier> {
+    use crate::rid::{
+        DOMAIN_ADMINS, DOMAIN_COMPUTERS, DOMAIN_CONTROLLERS, DOMAIN_GUESTS, DOMAIN_USERS,
+    };
+    [
+        DOMAIN_ADMINS,
+        DOMAIN_USERS,
+        DOMAIN_GUESTS,
+        DOMAIN_COMPUTERS,
+        DOMAIN_CONTROLLERS,
+    ]
+    .into_iter()
+    .map_while(|rid| {
+        let mut sid = crate::SecurityIdentifier::from(domain);
+        sid.push_sub_authority(rid).ok().map(|()| sid)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, reason = "Unwrap is not an issue in test")]
+mod tests {
+    use super::*;
+    use crate::StackSid;
+
+    #[test]
+    fn aliases_match_canonical_sids() {
+        assert_eq!(EVERYONE.as_sid(), WORLD.as_sid());
+        assert_eq!(SYSTEM.as_sid(), LOCAL_SYSTEM.as_sid());
+    }
+
+    #[cfg(feature = "service-sid")]
+    #[test]
+    fn service_sid_matches_known_services() {
+        assert_eq!(service_sid("TrustedInstaller"), TRUSTED_INSTALLER);
+        // Service names are case-insensitive.
+        assert_eq!(
+            service_sid("trustedinstaller"),
+            service_sid("TRUSTEDINSTALLER")
+        );
+        assert!(service_sid("").as_sid().is_service_sid());
+    }
+
+    #[cfg(all(feature = "service-sid", feature = "alloc"))]
+    #[test]
+    fn service_sid_from_name_known_services() {
+        assert_eq!(service_sid_from_name("TrustedInstaller"), TRUSTED_INSTALLER);
+        assert_eq!(
+            service_sid_from_name("MSSQLSERVER"),
+            "S-1-5-80-3880718306-3832830129-1677859214-2598158968-1052248003"
+        );
+        assert_eq!(
+            service_sid_from_name("WinDefend"),
+            "S-1-5-80-1913148863-3492339771-4165695881-2087618961-4109116736"
+        );
+        assert!(service_sid_from_name("windefend").is_service_sid());
+    }
+
+    #[test]
+    fn trusted_installer_string_form() {
+        assert!(
+            TRUSTED_INSTALLER
+                .as_sid()
+                .eq_str("S-1-5-80-956008885-3418522649-1831038044-1853292631-2271478464")
+        );
+        assert!(TRUSTED_INSTALLER.as_sid().is_service_sid());
+    }
+
+    #[test]
+    fn sid_statics_in_static_table() {
+        static PRIVILEGED: [&Sid; 3] = [
+            LOCAL_SYSTEM_SID,
+            BUILTIN_ADMINISTRATORS_SID,
+            TRUSTED_INSTALLER_SID,
+        ];
+        assert_eq!(PRIVILEGED[1], BUILTIN_ADMINISTRATORS.as_sid());
+        assert!(PRIVILEGED.contains(&SYSTEM_SID));
+        assert!(!PRIVILEGED.contains(&BUILTIN_USERS_SID));
+        assert_eq!(EVERYONE_SID, WORLD_SID);
+    }
+
+    #[test]
+    fn well_known_groups() {
+        for group in WELL_KNOWN_GROUPS {
+            assert!(group.is_well_known_group(), "{group}");
+        }
+        assert!(EVERYONE.as_sid().is_well_known_group());
+        assert!(AUTHENTICATED_USERS.as_sid().eq_str("S-1-5-11"));
+        assert!(INTERACTIVE.as_sid().eq_str("S-1-5-4"));
+        assert!(!LOCAL_SYSTEM.as_sid().is_well_known_group());
+        assert!(!NULL.as_sid().is_well_known_group());
+        assert!(!BUILTIN_ADMINISTRATORS.as_sid().is_well_known_group());
+    }
+
+    #[test]
+    fn by_name_matches_names() {
+        assert_eq!(by_name("Everyone"), Some(EVERYONE_SID));
+        assert_eq!(by_name("EVERYONE"), Some(EVERYONE_SID));
+        assert_eq!(
+            by_name("BUILTIN\\Administrators"),
+            Some(BUILTIN_ADMINISTRATORS_SID)
+        );
+        assert_eq!(by_name("builtin\\users"), Some(BUILTIN_USERS_SID));
+        assert_eq!(
+            by_name("nt authority\\authenticated users"),
+            Some(AUTHENTICATED_USERS_SID)
+        );
+        assert_eq!(by_name("Local Service"), Some(LOCAL_SERVICE_SID));
+        assert_eq!(by_name("TrustedInstaller"), Some(TRUSTED_INSTALLER_SID));
+        assert_eq!(by_name("BUILTIN\\Everyone"), None);
+        assert_eq!(by_name("BUILTIN\\"), None);
+        assert_eq!(by_name(""), None);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for (name, sid) in NAMES {
+            assert_eq!(by_name(name), Some(sid));
+            assert_eq!(well_known_name(sid), Some(name));
+        }
+        assert_eq!(
+            well_known_name(TRUSTED_INSTALLER_SID),
+            Some("NT SERVICE\\TrustedInstaller")
+        );
+        let user: StackSid = "S-1-5-21-1-2-3-1001".parse().unwrap();
+        assert_eq!(well_known_name(user.as_sid()), None);
+    }
+
+    #[test]
+    fn capability_constants() {
+        let capabilities = [
+            INTERNET_CLIENT,
+            INTERNET_CLIENT_SERVER,
+            PRIVATE_NETWORK_CLIENT_SERVER,
+            PICTURES_LIBRARY,
+            VIDEOS_LIBRARY,
+            MUSIC_LIBRARY,
+            DOCUMENTS_LIBRARY,
+            ENTERPRISE_AUTHENTICATION,
+            SHARED_USER_CERTIFICATES,
+            REMOVABLE_STORAGE,
+        ];
+        for (rid, capability) in (1..).zip(capabilities) {
+            assert!(capability.as_sid().is_capability());
+            assert_eq!(capability.as_sid().rid(), rid);
+        }
+        assert_eq!("S-1-15-3-1".parse::<StackSid>().unwrap(), INTERNET_CLIENT);
+    }
+
+    #[test]
+    fn asserted_identity_string_round_trip() {
+        for (sid, text) in [
+            (AUTHENTICATION_AUTHORITY_ASSERTED_IDENTITY, "S-1-18-1"),
+            (SERVICE_ASSERTED_IDENTITY, "S-1-18-2"),
+        ] {
+            assert_eq!(sid.to_string(), text);
+            assert_eq!(text.parse::<StackSid>().unwrap(), sid);
+            assert_eq!(
+                sid.as_sid().authority(),
+                SidIdentifierAuthority::ASSERTED_IDENTITY_AUTHORITY
+            );
+        }
+        assert!(SERVICE_ASSERTED_IDENTITY_SID.eq_str("S-1-18-2"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn expand_domain_groups_synthetic_domain() {
+        let domain: StackSid = "S-1-5-21-1004336348-1177238915-682003330".parse().unwrap();
+        let groups: Vec<String> = expand_domain_groups(domain.as_sid())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                "S-1-5-21-1004336348-1177238915-682003330-512",
+                "S-1-5-21-1004336348-1177238915-682003330-513",
+                "S-1-5-21-1004336348-1177238915-682003330-514",
+                "S-1-5-21-1004336348-1177238915-682003330-515",
+                "S-1-5-21-1004336348-1177238915-682003330-516",
+            ]
+        );
+
+        let full = StackSid::try_new(SidIdentifierAuthority::NT_AUTHORITY, &[1; 15]).unwrap();
+        assert!(expand_domain_groups(full.as_sid()).is_empty());
+    }
+
+    #[test]
+    fn aliases_parse() {
+        assert_eq!("S-1-1-0".parse::<StackSid>().unwrap(), EVERYONE);
+        assert_eq!("S-1-5-18".parse::<StackSid>().unwrap(), SYSTEM);
+    }
+}
@@ -12,7 +12,7 @@ use std::{
     process::{Command, Stdio},
 };
 use win_security_identifier::{
-    GetCurrentSid, SecurityIdentifier, Sid, StackSid,
+    GetCurrentSid, OwnedSid, SecurityIdentifier, StackSid,
     sid_lookup::{DomainAndName, SidType},
 };
 
@@ -48,8 +48,7 @@ fn current_user_sid_and_account_stack() {
 
 fn current_user_sid_and_account<T>()
 where
-    T: Sized + AsRef<Sid> + PartialEq<StackSid> + Debug,
-    for<'a> &'a Sid: Into<T>,
+    T: OwnedSid + PartialEq<StackSid> + Debug,
 {
     const PS_SCRIPT: &str = include_str!("assets/get_sid_account.ps1");
 
@@ -0,0 +1,77 @@
+// Cross-type harness: the same logical SID must compare equal and hash identically
+// whatever its representation (`Sid`, `SecurityIdentifier`, `StackSid`, `ConstSid`).
+#![cfg(feature = "std")]
+#![allow(clippy::unwrap_used, reason = "Unwrap is not an issue in tests")]
+#![allow(clippy::std_instead_of_core)]
+
+use core::hash::{Hash, Hasher};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use std::hash::DefaultHasher;
+use win_security_identifier::{
+    ConstSid, SecurityIdentifier, Sid, SidIdentifierAuthority, StackSid,
+};
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn check_symmetric<A, B>(a: &A, b: &B, what: &str) -> Result<(), TestCaseError>
+where
+    A: PartialEq<B> + ?Sized,
+    B: PartialEq<A> + ?Sized,
+{
+    prop_assert!(a.eq(b), "{} (forward)", what);
+    prop_assert!(b.eq(a), "{} (backward)", what);
+    Ok(())
+}
+
+fn check_consistency<C>(const_sid: &C) -> Result<(), TestCaseError>
+where
+    C: AsRef<Sid> + Hash + PartialEq<Sid> + PartialEq<SecurityIdentifier> + PartialEq<StackSid>,
+    Sid: PartialEq<C> + PartialEq<SecurityIdentifier> + PartialEq<StackSid>,
+    SecurityIdentifier: PartialEq<C> + PartialEq<Sid> + PartialEq<StackSid>,
+    StackSid: PartialEq<C> + PartialEq<Sid> + PartialEq<SecurityIdentifier>,
+{
+    let sid: &Sid = const_sid.as_ref();
+    let owned = SecurityIdentifier::from(sid);
+    let stack = StackSid::from(sid);
+
+    // Pairwise equality, in both directions.
+    check_symmetric(sid, &owned, "Sid <-> SecurityIdentifier")?;
+    check_symmetric(sid, &stack, "Sid <-> StackSid")?;
+    check_symmetric(sid, const_sid, "Sid <-> ConstSid")?;
+    check_symmetric(&owned, &stack, "SecurityIdentifier <-> StackSid")?;
+    check_symmetric(&owned, const_sid, "SecurityIdentifier <-> ConstSid")?;
+    check_symmetric(&stack, const_sid, "StackSid <-> ConstSid")?;
+
+    // Identical hashes.
+    let expected = hash_of(sid);
+    prop_assert_eq!(hash_of(&owned), expected, "SecurityIdentifier hash");
+    prop_assert_eq!(hash_of(&stack), expected, "StackSid hash");
+    prop_assert_eq!(hash_of(const_sid), expected, "ConstSid hash");
+    Ok(())
+}
+
+/// Dispatches on the sub-authority count to build a `ConstSid<N>` with a concrete `N`.
+macro_rules! check_for_len {
+    ($authority:expr, $subs:expr; $($n:literal),* $(,)?) => {
+        match $subs.len() {
+            $($n => check_consistency(&ConstSid::<$n>::new($authority, $subs.try_into().unwrap())),)*
+            len => Err(TestCaseError::fail(format!("unexpected sub-authority count {len}"))),
+        }
+    };
+}
+
+proptest! {
+    #[test]
+    fn same_sid_same_hash_and_eq(
+        authority in any::<[u8; 6]>(),
+        subs in proptest::collection::vec(any::<u32>(), 1..=15),
+    ) {
+        let authority = SidIdentifierAuthority::new(authority);
+        check_for_len!(authority, subs.as_slice(); 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)?;
+    }
+}
@@ -0,0 +1,44 @@
+// Windows-only integration test exercising the `LookupAccountSidW` error mapping
+#![cfg(windows)]
+#![cfg(feature = "std")]
+#![allow(clippy::expect_used, reason = "Expect is not an issue in tests")]
+#![allow(clippy::unwrap_used, reason = "Unwrap is not an issue in tests")]
+
+use win_security_identifier::{
+    GetCurrentSid, SecurityIdentifier, SidIdentifierAuthority, sid_lookup::Error,
+};
+
+/// Highest RID; never handed out by a SAM or AD domain.
+const BOGUS_RID: u32 = u32::MAX;
+
+/// Builds a SID that no account maps to on this machine.
+///
+/// When the current user belongs to a domain (`S-1-5-21-x-y-z-rid`), the
+/// bogus RID is put under that same domain so the lookup is answered by an
+/// authority the machine can reach; otherwise a made-up domain is used.
+fn unmappable_sid() -> SecurityIdentifier {
+    let current = SecurityIdentifier::get_current_user_sid().expect("current user SID");
+    let mut sub_authorities = match current.get_sub_authorities() {
+        domain_account @ [21, _, _, _, _]
+            if current.identifier_authority == SidIdentifierAuthority::NT_AUTHORITY =>
+        {
+            domain_account.to_vec()
+        }
+        _ => vec![21, 1_234_567_890, 987_654_321, 1_122_334_455, 0],
+    };
+    if let Some(rid) = sub_authorities.last_mut() {
+        *rid = BOGUS_RID;
+    }
+    SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, sub_authorities).unwrap()
+}
+
+#[test]
+fn lookup_unmappable_sid_reports_none_mapped() {
+    let sid = unmappable_sid();
+    let result = sid.lookup_local_sid().expect("lookup should run");
+    assert_eq!(
+        result.err(),
+        Some(Error::NoneMapped),
+        "unexpected result for {sid}"
+    );
+}
@@ -0,0 +1,33 @@
+// Windows-only integration test for `LsaLookupSids2` based lookups
+#![cfg(windows)]
+#![cfg(feature = "std")]
+#![allow(clippy::expect_used, reason = "Expect is not an issue in tests")]
+#![allow(clippy::unwrap_used, reason = "Unwrap is not an issue in tests")]
+
+use win_security_identifier::{
+    GetCurrentSid, SecurityIdentifier, SidIdentifierAuthority, sid_lookup::SidType,
+};
+
+/// The current user is either a domain or a local account (`S-1-5-21-x-y-z-rid`);
+/// in both cases its domain SID is the SID without the RID.
+#[test]
+fn current_user_reports_its_domain_sid() {
+    let sid = SecurityIdentifier::get_current_user_sid().expect("current user SID");
+    let full = sid.lookup_local_sid_full().expect("lookup should succeed");
+    let local = sid.lookup_local_sid().unwrap().unwrap();
+    assert_eq!(full.name, local.domain_name.name);
+    assert_eq!(full.domain, local.domain_name.domain);
+
+    if let [21, domain @ .., _] = sid.get_sub_authorities()
+        && sid.identifier_authority == SidIdentifierAuthority::NT_AUTHORITY
+    {
+        assert_eq!(full.sid_type, Some(SidType::User));
+        let mut expected = vec![21];
+        expected.extend_from_slice(domain);
+        let domain_sid =
+            SecurityIdentifier::try_new(SidIdentifierAuthority::NT_AUTHORITY, expected).unwrap();
+        assert_eq!(full.domain_sid, Some(domain_sid));
+    } else {
+        assert!(full.domain_sid.is_some(), "no domain SID for {sid}");
+    }
+}
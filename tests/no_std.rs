@@ -0,0 +1,61 @@
+// Checks that SIDs can be decoded and compared from a `no_std` crate without `alloc`.
+#![no_std]
+#![allow(clippy::unwrap_used, reason = "Unwrap is not an issue in tests")]
+
+use win_security_identifier::domain_and_name::{DomainAndNameRef, DomainParsingError};
+use win_security_identifier::{
+    InvalidSidFormat, ParseError, SidIdentifierAuthority, StackSid, parse_sid_bytes, well_known,
+};
+
+#[test]
+fn parse_sid_bytes_without_alloc() {
+    let bytes = well_known::BUILTIN_ADMINISTRATORS.as_sid().as_binary();
+    let sid = parse_sid_bytes(bytes).unwrap();
+    assert_eq!(sid, well_known::BUILTIN_ADMINISTRATORS);
+    assert_eq!(sid.as_binary(), bytes);
+    assert_eq!(parse_sid_bytes(&[1, 0]), Err(InvalidSidFormat));
+}
+
+#[test]
+fn eq_str_without_alloc() {
+    let admins = well_known::BUILTIN_ADMINISTRATORS;
+    assert!(admins.as_sid().eq_str("S-1-5-32-544"));
+    assert!(!admins.as_sid().eq_str("S-1-5-32-545"));
+    assert!(!admins.as_sid().eq_str("s-1-5-32-544"));
+    assert!(!admins.as_sid().eq_str("S-1-5-32-544-"));
+    assert!(!admins.as_sid().eq_str(""));
+
+    // Hex authority and 15 maximal sub-authorities: the longest SID string.
+    let longest =
+        StackSid::try_new(SidIdentifierAuthority::new([0xFF; 6]), &[u32::MAX; 15]).unwrap();
+    assert!(longest.as_sid().eq_str(concat!(
+        "S-1-0xFFFFFFFFFFFF",
+        "-4294967295-4294967295-4294967295-4294967295-4294967295",
+        "-4294967295-4294967295-4294967295-4294967295-4294967295",
+        "-4294967295-4294967295-4294967295-4294967295-4294967295",
+    )));
+    assert!(!longest.as_sid().eq_str("S-1-0xFFFFFFFFFFFF-4294967295"));
+}
+
+fn parse_account<'a>(sid: &str, account: &'a str) -> Result<(StackSid, &'a str), ParseError> {
+    let sid: StackSid = sid.parse()?;
+    let account = DomainAndNameRef::parse(account)?;
+    Ok((sid, account.name))
+}
+
+#[test]
+fn parse_error_unifies_sid_and_domain_errors() {
+    let (sid, name) = parse_account("S-1-5-18", r"NT AUTHORITY\SYSTEM").unwrap();
+    assert_eq!(sid, well_known::LOCAL_SYSTEM);
+    assert_eq!(name, "SYSTEM");
+    assert_eq!(
+        parse_account("S-1-5", r"NT AUTHORITY\SYSTEM"),
+        Err(ParseError::Sid(InvalidSidFormat))
+    );
+    assert_eq!(
+        parse_account("S-1-5-18", "SYSTEM"),
+        Err(ParseError::DomainAndName(
+            DomainParsingError::MissingSeparator
+        ))
+    );
+}